@@ -0,0 +1,406 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+//! Helpers for processing recorded frames.
+
+/// Scale between `i16` samples and normalized floating-point samples in `[-1.0, 1.0)`.
+const I16_SCALE: f64 = 32768.0;
+
+/// Lowest level tracked by [`EnergyHistogram`], in dBFS; quieter frames share its first bucket.
+const HISTOGRAM_MIN_DB: f32 = -100.0;
+/// Width of each [`EnergyHistogram`] bucket, in dB.
+const HISTOGRAM_BUCKET_DB: f32 = 0.5;
+const HISTOGRAM_BUCKETS: usize = 200;
+
+/// Converts `i16` samples to normalized `f64` samples in `[-1.0, 1.0)`.
+#[must_use]
+pub fn i16_to_f64(samples: &[i16]) -> Vec<f64> {
+    samples
+        .iter()
+        .map(|&sample| f64::from(sample) / I16_SCALE)
+        .collect()
+}
+
+/// Converts `i16` samples to normalized `f32` samples in `[-1.0, 1.0)`, dividing by 32768.0.
+///
+/// This is the inverse of [`f32_to_i16`].
+#[must_use]
+pub fn i16_frames_to_f32(frames: &[i16]) -> Vec<f32> {
+    #[allow(clippy::cast_possible_truncation)]
+    let scale = I16_SCALE as f32;
+    frames
+        .iter()
+        .map(|&sample| f32::from(sample) / scale)
+        .collect()
+}
+
+/// Converts normalized `f32` samples back to `i16`, multiplying by 32768.0 and rounding.
+///
+/// Values outside `[-1.0, 1.0)` saturate to `i16::MIN` or `i16::MAX` instead of wrapping,
+/// and NaN becomes 0.
+#[must_use]
+pub fn f32_to_i16(samples: &[f32]) -> Vec<i16> {
+    #[allow(clippy::cast_possible_truncation)]
+    let scale = I16_SCALE as f32;
+    samples
+        .iter()
+        .map(|&sample| {
+            #[allow(clippy::cast_possible_truncation)]
+            let converted = (sample * scale).round() as i16;
+            converted
+        })
+        .collect()
+}
+
+/// Subtracts the mean of `samples` from every sample, in place.
+///
+/// The mean is computed over the given slice only, so applied to a frame this removes
+/// the DC offset of that frame. It is not a running high-pass filter and can introduce
+/// small steps at frame boundaries.
+pub fn remove_dc_offset(samples: &mut [f64]) {
+    if samples.is_empty() {
+        return;
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+    for sample in samples {
+        *sample -= mean;
+    }
+}
+
+/// Averages interleaved multichannel samples into a mono signal.
+///
+/// Each output sample is the sum of one sample per channel divided by `channels`,
+/// rounded to the nearest integer with ties away from zero. The average of `i16`
+/// values always fits in an `i16`, so no saturation occurs. A trailing partial group
+/// of samples is dropped. `channels` of 0 or 1 returns the input unchanged.
+#[must_use]
+pub fn downmix_to_mono(samples: &[i16], channels: usize) -> Vec<i16> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+
+    let divisor = i64::try_from(channels).unwrap_or(i64::MAX);
+    samples
+        .chunks_exact(channels)
+        .map(|group| {
+            let sum: i64 = group.iter().map(|&sample| i64::from(sample)).sum();
+            let rounded = if sum >= 0 {
+                (sum + divisor / 2) / divisor
+            } else {
+                (sum - divisor / 2) / divisor
+            };
+            i16::try_from(rounded).unwrap_or(if rounded < 0 { i16::MIN } else { i16::MAX })
+        })
+        .collect()
+}
+
+/// Returns the largest absolute sample value, saturating `i16::MIN` to `i16::MAX`.
+#[must_use]
+pub fn peak(samples: &[i16]) -> i16 {
+    samples
+        .iter()
+        .map(|sample| sample.saturating_abs())
+        .max()
+        .unwrap_or(0)
+}
+
+/// Returns the root-mean-square level of `samples`, normalized to `[0.0, 1.0]`.
+///
+/// Samples are scaled by 1/32768 as in [`i16_to_f64`], so a full-scale square wave has
+/// an RMS of 1.0. Returns 0.0 for an empty slice.
+#[must_use]
+pub fn rms(samples: &[i16]) -> f32 {
+    if samples.is_empty() {
+        return 0.0;
+    }
+    let sum_of_squares: f64 = samples
+        .iter()
+        .map(|&sample| (f64::from(sample) / I16_SCALE).powi(2))
+        .sum();
+    #[allow(clippy::cast_precision_loss, clippy::cast_possible_truncation)]
+    let rms = (sum_of_squares / samples.len() as f64).sqrt() as f32;
+    rms
+}
+
+/// Multiplies every sample by `gain`, rounding and saturating to the `i16` range.
+pub fn apply_gain(samples: &mut [i16], gain: f32) {
+    for sample in samples {
+        #[allow(clippy::cast_possible_truncation)]
+        let scaled = (f32::from(*sample) * gain).round() as i16;
+        *sample = scaled;
+    }
+}
+
+/// Shortens `samples` to at most `len` samples, keeping the beginning.
+///
+/// Shorter input is returned unchanged. Use [`pad_to`] to get exactly `len` samples.
+#[must_use]
+pub fn trim_to(mut samples: Vec<i16>, len: usize) -> Vec<i16> {
+    samples.truncate(len);
+    samples
+}
+
+/// Returns exactly `len` samples: `samples` is truncated if longer, or extended with
+/// `fill` (typically 0) if shorter.
+#[must_use]
+pub fn pad_to(mut samples: Vec<i16>, len: usize, fill: i16) -> Vec<i16> {
+    samples.resize(len, fill);
+    samples
+}
+
+/// Resamples `samples` from `from_rate` to `to_rate` Hz by linear interpolation.
+///
+/// The output has `samples.len() * to_rate / from_rate` samples, rounded to the
+/// nearest integer. Each call treats its input in isolation, so resampling frame by
+/// frame can introduce small discontinuities at frame boundaries, and no anti-aliasing
+/// filter is applied when downsampling. It is meant for monitoring and debugging, not
+/// for high-quality conversion. Either rate being 0 returns an empty vector.
+#[must_use]
+pub fn resample_linear(samples: &[i16], from_rate: usize, to_rate: usize) -> Vec<i16> {
+    if from_rate == 0 || to_rate == 0 || samples.is_empty() {
+        return Vec::new();
+    }
+    if from_rate == to_rate {
+        return samples.to_vec();
+    }
+
+    let len = (samples.len() * to_rate + from_rate / 2) / from_rate;
+    #[allow(clippy::cast_precision_loss)]
+    let step = from_rate as f64 / to_rate as f64;
+    let last = samples.len() - 1;
+    (0..len)
+        .map(|i| {
+            #[allow(clippy::cast_precision_loss)]
+            let position = i as f64 * step;
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            let index = (position.floor() as usize).min(last);
+            let fraction = position - position.floor();
+            let current = f64::from(samples[index]);
+            let next = f64::from(samples[(index + 1).min(last)]);
+            #[allow(clippy::cast_possible_truncation)]
+            let sample = (current + (next - current) * fraction).round() as i16;
+            sample
+        })
+        .collect()
+}
+
+/// Downsamples `samples` by an integer `factor`, averaging each group of `factor`
+/// samples into one.
+///
+/// The output has `samples.len() / factor` samples, rounded up: a trailing partial group
+/// is averaged on its own. The averaging acts as a crude anti-aliasing filter, so e.g.
+/// 16 kHz to 8 kHz is `decimate(samples, 2)`. A `factor` of 0 returns an empty vector.
+#[must_use]
+pub fn decimate(samples: &[i16], factor: usize) -> Vec<i16> {
+    if factor == 0 {
+        return Vec::new();
+    }
+
+    samples
+        .chunks(factor)
+        .map(|group| {
+            let sum: i64 = group.iter().map(|&sample| i64::from(sample)).sum();
+            #[allow(clippy::cast_possible_wrap)]
+            let len = group.len() as i64;
+            // Round half away from zero; the mean of `i16` values always fits in an `i16`.
+            let mean = (sum + sum.signum() * len / 2) / len;
+            i16::try_from(mean).unwrap_or_default()
+        })
+        .collect()
+}
+
+/// Reduces `samples` to `buckets` `(min, max)` pairs for drawing a filled waveform.
+///
+/// The samples are split into `buckets` contiguous ranges of nearly equal size, and
+/// the minimum and maximum of each range are returned in order. If `buckets` exceeds
+/// the number of samples, one pair per sample is returned instead. An empty input or
+/// zero `buckets` returns an empty vector.
+#[must_use]
+pub fn decimate_minmax(samples: &[i16], buckets: usize) -> Vec<(i16, i16)> {
+    let buckets = buckets.min(samples.len());
+    (0..buckets)
+        .map(|bucket| {
+            let start = bucket * samples.len() / buckets;
+            let end = (bucket + 1) * samples.len() / buckets;
+            samples[start..end]
+                .iter()
+                .fold((i16::MAX, i16::MIN), |(min, max), &sample| {
+                    (min.min(sample), max.max(sample))
+                })
+        })
+        .collect()
+}
+
+/// Accumulates the distribution of frame [`rms`] levels, e.g. to derive a silence
+/// threshold from a few seconds of room noise.
+///
+/// Levels are bucketed in 0.5 dB steps from -100 dBFS to 0 dBFS, so quiet and loud
+/// inputs get the same relative resolution; anything quieter, including digital
+/// silence, falls in the lowest bucket. No samples are stored.
+///
+/// # Example
+/// ```no_run
+/// use pv_recorder::analysis::EnergyHistogram;
+/// use pv_recorder::PvRecorderBuilder;
+///
+/// let recorder = PvRecorderBuilder::default().init()?;
+/// recorder.start()?;
+///
+/// let mut histogram = EnergyHistogram::new();
+/// for _ in 0..100 {
+///     histogram.observe(&recorder.read()?);
+/// }
+/// let silence_threshold = histogram.percentile(20.0);
+/// # Ok::<(), pv_recorder::PvRecorderError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct EnergyHistogram {
+    counts: Vec<u64>,
+    total: u64,
+}
+
+impl Default for EnergyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl EnergyHistogram {
+    /// Creates an empty histogram.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            counts: vec![0; HISTOGRAM_BUCKETS],
+            total: 0,
+        }
+    }
+
+    /// Adds the RMS level of `frame` to the histogram.
+    pub fn observe(&mut self, frame: &[i16]) {
+        let db = 20.0 * rms(frame).log10();
+        let position = ((db - HISTOGRAM_MIN_DB) / HISTOGRAM_BUCKET_DB).floor();
+        // Negative and NaN positions (digital silence) saturate to the first bucket.
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let bucket = (position as usize).min(HISTOGRAM_BUCKETS - 1);
+        self.counts[bucket] += 1;
+        self.total += 1;
+    }
+
+    /// Returns the RMS level, normalized like [`rms`], below which `p` percent of the
+    /// observed frames fall.
+    ///
+    /// `p` is clamped to `[0.0, 100.0]`. The result is the upper edge of the bucket
+    /// holding that percentile, so it is accurate to 0.5 dB and can be compared
+    /// directly with [`rms`]. Returns 0.0 before any frame is observed.
+    #[must_use]
+    pub fn percentile(&self, p: f32) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+
+        #[allow(
+            clippy::cast_precision_loss,
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss
+        )]
+        let rank = ((p.clamp(0.0, 100.0) / 100.0 * self.total as f32).ceil() as u64).max(1);
+        let mut cumulative = 0;
+        let bucket = self
+            .counts
+            .iter()
+            .position(|&count| {
+                cumulative += count;
+                cumulative >= rank
+            })
+            .unwrap_or(HISTOGRAM_BUCKETS - 1);
+
+        #[allow(clippy::cast_precision_loss)]
+        let upper_db = HISTOGRAM_MIN_DB + (bucket + 1) as f32 * HISTOGRAM_BUCKET_DB;
+        10.0_f32.powf(upper_db / 20.0)
+    }
+
+    /// Returns the number of frames observed.
+    #[must_use]
+    pub fn count(&self) -> u64 {
+        self.total
+    }
+
+    /// Forgets all observations.
+    pub fn clear(&mut self) {
+        self.counts.fill(0);
+        self.total = 0;
+    }
+}
+
+/// Computes the magnitude spectrum of a frame, from DC up to the Nyquist frequency.
+///
+/// A periodic Hann window is applied before the FFT to reduce spectral leakage.
+/// Magnitudes are normalized so that a full-scale sine centered on a bin reads about
+/// 1.0. The result has `samples.len() / 2 + 1` bins; bin `k` corresponds to
+/// `k * sample_rate / samples.len()` Hz (see [`spectrum_bin_frequency`]). Pass a whole
+/// frame of `frame_length` samples for a sensible bin resolution.
+///
+/// Requires the `spectrum` feature. An FFT plan is created on every call; for very
+/// high frame rates, plan once with `rustfft` directly.
+#[cfg(feature = "spectrum")]
+#[must_use]
+pub fn frame_spectrum(samples: &[i16]) -> Vec<f32> {
+    use rustfft::num_complex::Complex;
+
+    let len = samples.len();
+    if len == 0 {
+        return Vec::new();
+    }
+
+    #[allow(clippy::cast_precision_loss)]
+    let window_len = len as f32;
+    let mut window_sum = 0.0;
+    let mut buffer: Vec<Complex<f32>> = samples
+        .iter()
+        .enumerate()
+        .map(|(n, &sample)| {
+            #[allow(clippy::cast_precision_loss)]
+            let phase = std::f32::consts::TAU * n as f32 / window_len;
+            let window = 0.5 - 0.5 * phase.cos();
+            window_sum += window;
+            Complex::new(f32::from(sample) / 32768.0 * window, 0.0)
+        })
+        .collect();
+
+    rustfft::FftPlanner::new()
+        .plan_fft_forward(len)
+        .process(&mut buffer);
+
+    let scale = if window_sum > 0.0 {
+        2.0 / window_sum
+    } else {
+        0.0
+    };
+    buffer[..=len / 2]
+        .iter()
+        .map(|bin| bin.norm() * scale)
+        .collect()
+}
+
+/// Returns the center frequency in Hz of bin `bin` of a [`frame_spectrum`] computed
+/// over `frame_length` samples recorded at `sample_rate` Hz.
+#[cfg(feature = "spectrum")]
+#[must_use]
+pub fn spectrum_bin_frequency(bin: usize, frame_length: usize, sample_rate: usize) -> f32 {
+    if frame_length == 0 {
+        return 0.0;
+    }
+    #[allow(clippy::cast_precision_loss)]
+    let frequency = bin as f32 * sample_rate as f32 / frame_length as f32;
+    frequency
+}
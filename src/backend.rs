@@ -0,0 +1,42 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use crate::pvrecorder::{PvRecorder, PvRecorderError};
+
+/// A source of audio frames.
+///
+/// [`PvRecorder`] implements this trait, so code that only consumes frames can be
+/// written against `RecorderBackend` and exercised with a synthetic source (such as
+/// `testing::SineSource` with the `testing` feature) instead of a microphone.
+pub trait RecorderBackend {
+    /// Reads the next frame of `frame_length()` samples.
+    fn read(&mut self) -> Result<Vec<i16>, PvRecorderError>;
+
+    /// Returns the number of samples in each frame.
+    fn frame_length(&self) -> usize;
+
+    /// Returns the sample rate of the frames in Hz.
+    fn sample_rate(&self) -> usize;
+}
+
+impl RecorderBackend for PvRecorder {
+    fn read(&mut self) -> Result<Vec<i16>, PvRecorderError> {
+        PvRecorder::read(self)
+    }
+
+    fn frame_length(&self) -> usize {
+        PvRecorder::frame_length(self)
+    }
+
+    fn sample_rate(&self) -> usize {
+        PvRecorder::sample_rate(self)
+    }
+}
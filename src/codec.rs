@@ -0,0 +1,44 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use crate::pvrecorder::{PvRecorderError, PvRecorderErrorStatus};
+
+/// Serializes a frame of samples into little-endian bytes.
+///
+/// Each sample occupies two bytes, so the output is `samples.len() * 2` bytes long
+/// regardless of the host's native endianness.
+#[must_use]
+pub fn frame_to_le_bytes(samples: &[i16]) -> Vec<u8> {
+    samples
+        .iter()
+        .flat_map(|sample| sample.to_le_bytes())
+        .collect()
+}
+
+/// Reconstructs a frame of samples from little-endian bytes.
+///
+/// This is the inverse of [`frame_to_le_bytes`].
+///
+/// # Errors
+/// Returns an `ArgumentError` if `bytes` has an odd length.
+pub fn frame_from_le_bytes(bytes: &[u8]) -> Result<Vec<i16>, PvRecorderError> {
+    if bytes.len() % 2 != 0 {
+        return Err(PvRecorderError::new(
+            PvRecorderErrorStatus::ArgumentError,
+            format!("byte length must be even, got: {}", bytes.len()),
+        ));
+    }
+
+    Ok(bytes
+        .chunks_exact(2)
+        .map(|pair| i16::from_le_bytes([pair[0], pair[1]]))
+        .collect())
+}
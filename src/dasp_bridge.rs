@@ -0,0 +1,59 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use dasp::sample::{FromSample, Sample};
+use dasp::signal::{self, FromIterator};
+
+use crate::pvrecorder::{PvRecorder, PvRecorderError};
+
+impl PvRecorder {
+    /// Reads one frame and converts it to the `dasp` sample type `S`.
+    ///
+    /// Conversion uses `dasp`'s [`FromSample`], so e.g. `f32` samples are in `[-1.0, 1.0)`
+    /// and `i32` samples are scaled up to the full `i32` range. Requires the `dasp`
+    /// feature.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use pv_recorder::PvRecorderBuilder;
+    ///
+    /// let recorder = PvRecorderBuilder::default().init()?;
+    /// recorder.start()?;
+    /// let frame: Vec<f32> = recorder.read_dasp()?;
+    /// # Ok::<(), pv_recorder::PvRecorderError>(())
+    /// ```
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs.
+    pub fn read_dasp<S>(&self) -> Result<Vec<S>, PvRecorderError>
+    where
+        S: Sample + FromSample<i16>,
+    {
+        Ok(self.read()?.into_iter().map(S::from_sample).collect())
+    }
+}
+
+/// Wraps a recorded frame in a mono `dasp` [`Signal`](dasp::Signal).
+///
+/// The signal yields the frame's samples in order and then reports itself exhausted.
+/// Requires the `dasp` feature.
+///
+/// # Example
+/// ```
+/// use dasp::Signal;
+///
+/// let mut signal = pv_recorder::frame_signal(vec![1, 2, 3]);
+/// assert_eq!(signal.next(), 1);
+/// ```
+#[must_use]
+pub fn frame_signal(frame: Vec<i16>) -> FromIterator<std::vec::IntoIter<i16>> {
+    signal::from_iter(frame)
+}
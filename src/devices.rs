@@ -0,0 +1,120 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use crate::pvrecorder::{PvRecorderBuilder, PvRecorderError};
+
+/// An audio input device as reported by the pvrecorder library.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AudioDevice {
+    /// Index to pass to [`PvRecorderBuilder::device_index`].
+    pub index: i32,
+    /// Human-readable device name.
+    pub name: String,
+    /// Whether this is the device opened for index -1, i.e. the system default.
+    pub is_default: bool,
+}
+
+/// Returns the names of the available audio input devices, using the default library path.
+///
+/// Shorthand for `PvRecorderBuilder::default().get_available_devices()`.
+pub fn available_devices() -> Result<Vec<String>, PvRecorderError> {
+    PvRecorderBuilder::default().get_available_devices()
+}
+
+/// Returns the available audio input devices with their indices, using the default
+/// library path.
+///
+/// Shorthand for `PvRecorderBuilder::default().get_audio_devices()`.
+pub fn available_audio_devices() -> Result<Vec<AudioDevice>, PvRecorderError> {
+    PvRecorderBuilder::default().get_audio_devices()
+}
+
+/// Devices that appeared or disappeared between two [`DeviceWatcher::poll`] calls.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct DeviceChange {
+    /// Names of devices present now but not in the previous snapshot.
+    pub added: Vec<String>,
+    /// Names of devices present in the previous snapshot but not now.
+    pub removed: Vec<String>,
+}
+
+impl DeviceChange {
+    /// Returns `true` if no device was added or removed.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// Tracks the list of available audio input devices across polls.
+///
+/// This is a thin stateful wrapper over
+/// [`PvRecorderBuilder::get_available_devices`]: each [`poll`](Self::poll)
+/// re-enumerates the devices and reports the difference against the previous
+/// snapshot.
+///
+/// # Example
+/// ```no_run
+/// use pv_recorder::{DeviceWatcher, PvRecorderBuilder};
+///
+/// let mut watcher = DeviceWatcher::new(PvRecorderBuilder::default())?;
+/// let change = watcher.poll()?;
+/// for device in &change.added {
+///     println!("Connected: {device}");
+/// }
+/// for device in &change.removed {
+///     println!("Disconnected: {device}");
+/// }
+/// # Ok::<(), pv_recorder::PvRecorderError>(())
+/// ```
+pub struct DeviceWatcher {
+    builder: PvRecorderBuilder,
+    devices: Vec<String>,
+}
+
+impl DeviceWatcher {
+    /// Creates a watcher and takes the initial device snapshot.
+    ///
+    /// The builder is only used to enumerate devices, so its library path
+    /// determines which pvrecorder library is queried.
+    pub fn new(builder: PvRecorderBuilder) -> Result<Self, PvRecorderError> {
+        let devices = builder.get_available_devices()?;
+        Ok(Self { builder, devices })
+    }
+
+    /// Re-enumerates the devices and returns what changed since the last snapshot.
+    ///
+    /// The snapshot is only replaced when enumeration succeeds.
+    pub fn poll(&mut self) -> Result<DeviceChange, PvRecorderError> {
+        let devices = self.builder.get_available_devices()?;
+
+        let added = devices
+            .iter()
+            .filter(|device| !self.devices.contains(device))
+            .cloned()
+            .collect();
+        let removed = self
+            .devices
+            .iter()
+            .filter(|device| !devices.contains(device))
+            .cloned()
+            .collect();
+
+        self.devices = devices;
+        Ok(DeviceChange { added, removed })
+    }
+
+    /// Returns the device list from the most recent snapshot.
+    #[must_use]
+    pub fn devices(&self) -> &[String] {
+        &self.devices
+    }
+}
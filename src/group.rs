@@ -0,0 +1,111 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use std::thread;
+
+use crate::pvrecorder::{PvRecorder, PvRecorderBuilder, PvRecorderError, PvRecorderErrorStatus};
+
+/// Several recorders, one per device, read in lockstep.
+///
+/// Each device is opened with the same builder settings. [`read_all`](Self::read_all)
+/// reads one frame from every device concurrently, so the frames are read as close
+/// together as possible. Independent devices run on separate clocks, though, so
+/// sample-level synchronization is not guaranteed and the devices may drift apart
+/// over long recordings.
+///
+/// # Example
+/// ```no_run
+/// use pv_recorder::{PvRecorderBuilder, PvRecorderGroup};
+///
+/// let group = PvRecorderGroup::new(&[0, 1], &PvRecorderBuilder::new(512))?;
+/// group.start()?;
+/// let frames = group.read_all()?;
+/// assert_eq!(frames.len(), 2);
+/// group.stop()?;
+/// # Ok::<(), pv_recorder::PvRecorderError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct PvRecorderGroup {
+    recorders: Vec<PvRecorder>,
+}
+
+impl PvRecorderGroup {
+    /// Opens one recorder per device index, using `builder` for every other setting.
+    ///
+    /// The builder's own `device_index` and `select_device` are ignored.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if `indices` is empty, or the first error from
+    /// initializing a device, as for [`PvRecorderBuilder::init`].
+    pub fn new(indices: &[i32], builder: &PvRecorderBuilder) -> Result<Self, PvRecorderError> {
+        if indices.is_empty() {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                "PvRecorderGroup needs at least one device index",
+            ));
+        }
+
+        let recorders = indices
+            .iter()
+            .map(|&device_index| builder.init_device(device_index))
+            .collect::<Result<_, _>>()?;
+        Ok(Self { recorders })
+    }
+
+    /// Starts every recorder in the group.
+    ///
+    /// # Errors
+    /// Returns the first error from starting a recorder.
+    pub fn start(&self) -> Result<(), PvRecorderError> {
+        self.recorders.iter().try_for_each(PvRecorder::start)
+    }
+
+    /// Stops every recorder in the group, even if stopping one of them fails.
+    ///
+    /// # Errors
+    /// Returns the first error from stopping a recorder.
+    pub fn stop(&self) -> Result<(), PvRecorderError> {
+        self.recorders
+            .iter()
+            .map(PvRecorder::stop)
+            .fold(Ok(()), Result::and)
+    }
+
+    /// Reads one frame from each recorder, in the order of the device indices.
+    ///
+    /// The reads run concurrently on scoped threads.
+    ///
+    /// # Errors
+    /// Returns the first read error, in device order.
+    pub fn read_all(&self) -> Result<Vec<Vec<i16>>, PvRecorderError> {
+        thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .recorders
+                .iter()
+                .map(|recorder| scope.spawn(|| recorder.read()))
+                .collect();
+            handles
+                .into_iter()
+                .map(|handle| {
+                    handle
+                        .join()
+                        .unwrap_or_else(|panic| std::panic::resume_unwind(panic))
+                })
+                .collect()
+        })
+    }
+
+    /// Returns the recorders in the group, in the order of the device indices.
+    #[must_use]
+    pub fn recorders(&self) -> &[PvRecorder] {
+        &self.recorders
+    }
+}
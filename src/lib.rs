@@ -9,7 +9,35 @@
     specific language governing permissions and limitations under the License.
 */
 
+pub mod analysis;
+mod backend;
+mod codec;
+#[cfg(feature = "dasp")]
+mod dasp_bridge;
+mod devices;
+mod group;
+#[cfg(feature = "mmap")]
+mod mmap;
+mod overlap;
 mod pvrecorder;
+mod resampler;
+mod session;
+#[cfg(feature = "testing")]
+pub mod testing;
 mod util;
+#[cfg(feature = "wav")]
+mod wav;
 
+pub use crate::backend::*;
+pub use crate::codec::*;
+#[cfg(feature = "dasp")]
+pub use crate::dasp_bridge::*;
+pub use crate::devices::*;
+pub use crate::group::*;
+pub use crate::overlap::*;
 pub use crate::pvrecorder::*;
+pub use crate::resampler::*;
+pub use crate::session::*;
+pub use crate::util::{set_warning_handler, WarningHandler};
+#[cfg(feature = "wav")]
+pub use crate::wav::*;
@@ -0,0 +1,109 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use std::fs::{File, OpenOptions};
+use std::path::Path;
+
+use memmap2::MmapMut;
+
+use crate::pvrecorder::{PvRecorder, PvRecorderError, PvRecorderErrorStatus};
+
+const BYTES_PER_SAMPLE: usize = 2;
+
+fn mmap_error(err: &std::io::Error) -> PvRecorderError {
+    PvRecorderError::new(
+        PvRecorderErrorStatus::OtherError,
+        format!("Failed to write memory-mapped file: {err}"),
+    )
+}
+
+impl PvRecorder {
+    /// Records into a memory-mapped file as raw little-endian 16-bit PCM.
+    ///
+    /// The file at `path` is created (or truncated) and sized for `max_samples`
+    /// samples, then frames are copied straight into the mapping as they are read, so
+    /// hours of audio never have to fit in memory. Requires the `mmap` feature.
+    ///
+    /// The recorder must already be started. Recording ends once `max_samples` samples
+    /// are written or the recorder is stopped from another thread. Either way, and also
+    /// when a read fails, the mapping is flushed to disk and the file is truncated to
+    /// the samples actually written, so it never ends in unwritten zeros.
+    ///
+    /// Returns the number of samples written.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if the recorder is not recording, an `OtherError` if
+    /// the file cannot be created, mapped, flushed or truncated, or the read error that
+    /// ended recording.
+    pub fn record_to_mmap(
+        &self,
+        path: &Path,
+        max_samples: usize,
+    ) -> Result<usize, PvRecorderError> {
+        if !self.is_recording() {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                "record_to_mmap requires a started recorder",
+            ));
+        }
+
+        let len = max_samples.checked_mul(BYTES_PER_SAMPLE).ok_or_else(|| {
+            PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!("max_samples is too large: {max_samples}"),
+            )
+        })?;
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)
+            .map_err(|err| mmap_error(&err))?;
+        file.set_len(len as u64).map_err(|err| mmap_error(&err))?;
+        if max_samples == 0 {
+            return Ok(0);
+        }
+
+        // SAFETY: the file was just created by us; concurrent modification by other
+        // processes is outside this crate's control, as with any memory map.
+        let mut map = unsafe { MmapMut::map_mut(&file) }.map_err(|err| mmap_error(&err))?;
+
+        let mut written = 0;
+        let mut result = Ok(());
+        while written < max_samples && self.is_recording() {
+            let frame = match self.read() {
+                Ok(frame) => frame,
+                Err(err) => {
+                    result = Err(err);
+                    break;
+                }
+            };
+            let count = frame.len().min(max_samples - written);
+            let bytes = &mut map[written * BYTES_PER_SAMPLE..(written + count) * BYTES_PER_SAMPLE];
+            for (chunk, sample) in bytes.chunks_exact_mut(BYTES_PER_SAMPLE).zip(&frame) {
+                chunk.copy_from_slice(&sample.to_le_bytes());
+            }
+            written += count;
+        }
+
+        finish(&file, map, written)?;
+        result.map(|()| written)
+    }
+}
+
+/// Flushes the mapping, unmaps it and truncates the file to `written` samples.
+fn finish(file: &File, map: MmapMut, written: usize) -> Result<(), PvRecorderError> {
+    map.flush().map_err(|err| mmap_error(&err))?;
+    drop(map);
+    file.set_len((written * BYTES_PER_SAMPLE) as u64)
+        .map_err(|err| mmap_error(&err))
+}
@@ -0,0 +1,99 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use crate::pvrecorder::{PvRecorderError, PvRecorderErrorStatus};
+
+/// Regroups frames into fixed-size windows that advance by a fixed hop.
+///
+/// Frames of any length are fed with [`push`](Self::push), and complete windows are
+/// taken with [`next_window`](Self::next_window). The window and hop do not need to
+/// divide the recorder's `frame_length`: samples carry over between pushes, and a
+/// single push can complete several windows. A hop equal to half the window gives 50%
+/// overlap; a hop larger than the window skips the samples in between.
+///
+/// # Example
+/// ```no_run
+/// use pv_recorder::{OverlapBuffer, PvRecorderBuilder};
+///
+/// let recorder = PvRecorderBuilder::default().init()?;
+/// recorder.start()?;
+///
+/// let mut windows = OverlapBuffer::new(400, 200)?;
+/// loop {
+///     windows.push(&recorder.read()?);
+///     while let Some(window) = windows.next_window() {
+///         // process a 400-sample window
+///     }
+/// }
+/// # Ok::<(), pv_recorder::PvRecorderError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct OverlapBuffer {
+    window: usize,
+    hop: usize,
+    samples: Vec<i16>,
+    skip: usize,
+}
+
+impl OverlapBuffer {
+    /// Creates a buffer producing windows of `window` samples, each starting `hop`
+    /// samples after the previous one.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if `window` or `hop` is 0.
+    pub fn new(window: usize, hop: usize) -> Result<Self, PvRecorderError> {
+        if window == 0 || hop == 0 {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!("window and hop must be greater than 0, got: window {window}, hop {hop}"),
+            ));
+        }
+
+        Ok(Self {
+            window,
+            hop,
+            samples: Vec::with_capacity(window + hop),
+            skip: 0,
+        })
+    }
+
+    /// Appends a frame of samples.
+    pub fn push(&mut self, frame: &[i16]) {
+        let skipped = self.skip.min(frame.len());
+        self.skip -= skipped;
+        self.samples.extend_from_slice(&frame[skipped..]);
+    }
+
+    /// Returns the next complete window, or `None` until enough samples have been pushed.
+    pub fn next_window(&mut self) -> Option<Vec<i16>> {
+        if self.samples.len() < self.window {
+            return None;
+        }
+
+        let window = self.samples[..self.window].to_vec();
+        let drained = self.hop.min(self.samples.len());
+        self.samples.drain(..drained);
+        self.skip = self.hop - drained;
+        Some(window)
+    }
+
+    /// Returns the number of samples buffered towards the next window.
+    #[must_use]
+    pub fn buffered(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Discards all buffered samples, e.g. at an utterance boundary.
+    pub fn clear(&mut self) {
+        self.samples.clear();
+        self.skip = 0;
+    }
+}
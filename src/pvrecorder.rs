@@ -9,28 +9,45 @@
     specific language governing permissions and limitations under the License.
 */
 
+use std::collections::VecDeque;
 use std::ffi::CStr;
+use std::mem::MaybeUninit;
 use std::path::Path;
-use std::ptr::{addr_of_mut, NonNull};
-use std::sync::Arc;
+use std::ptr::{self, addr_of_mut, NonNull};
+use std::slice;
+use std::sync::atomic::{AtomicBool, AtomicI16, AtomicU32, AtomicU64, AtomicUsize, Ordering};
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::{Arc, Mutex, OnceLock, PoisonError, RwLock, RwLockReadGuard};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
 use std::{cmp::PartialEq, path::PathBuf};
 
 use libc::{c_char, c_int};
 use libloading::{Library, Symbol};
 
-use crate::util::pv_library_path;
+use crate::analysis;
+use crate::devices::AudioDevice;
+use crate::util::{
+    detect_audio_backend, duration_to_samples, pv_arm_library, pv_library_path, pv_library_path_in,
+    pv_platform, pv_platform_library, samples_to_duration, warn, EnvVarGuard, StderrGuard,
+};
 
 #[cfg(unix)]
 use libloading::os::unix::Symbol as RawSymbol;
 #[cfg(windows)]
 use libloading::os::windows::Symbol as RawSymbol;
 
+/// Opaque handle to a recorder object owned by the pvrecorder C library.
+///
+/// Only ever used behind a pointer, as returned by [`PvRecorder::as_raw`].
 #[repr(C)]
-struct CPvRecorder {}
+pub struct CPvRecorder {
+    _private: [u8; 0],
+}
 
-/// Status codes returned by the PvRecorder C library.
+/// Status codes returned by the `PvRecorder` C library.
 #[repr(C)]
-#[derive(PartialEq, Eq, Clone, Copy, Debug)]
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy, Debug)]
 #[allow(non_camel_case_types)]
 pub enum PvRecorderStatus {
     SUCCESS = 0,
@@ -73,7 +90,7 @@ type PvRecorderFreeAvailableDevicesList =
 type PvRecorderSampleRate = unsafe extern "C" fn() -> i32;
 type PvRecorderVersion = unsafe extern "C" fn() -> *const c_char;
 
-/// Categorization of errors that can occur with PvRecorder.
+/// Categorization of errors that can occur with `PvRecorder`.
 #[derive(Clone, Debug)]
 pub enum PvRecorderErrorStatus {
     /// Error returned by the underlying C library.
@@ -86,7 +103,7 @@ pub enum PvRecorderErrorStatus {
     OtherError,
 }
 
-/// Error type for PvRecorder operations.
+/// Error type for `PvRecorder` operations.
 #[derive(Clone, Debug)]
 pub struct PvRecorderError {
     status: PvRecorderErrorStatus,
@@ -124,9 +141,42 @@ impl std::fmt::Display for PvRecorderError {
 
 impl std::error::Error for PvRecorderError {}
 
+impl From<PvRecorderStatus> for PvRecorderError {
+    /// Wraps a raw library status in a `LibraryError` with a generic message.
+    ///
+    /// The status is not checked, so only convert statuses other than `SUCCESS`.
+    fn from(status: PvRecorderStatus) -> Self {
+        Self::new(
+            PvRecorderErrorStatus::LibraryError(status),
+            format!("pvrecorder library returned {status:?}"),
+        )
+    }
+}
+
+type DeviceSelector = Box<dyn Fn(&AudioDevice) -> bool + Send + Sync>;
+
+/// Observer called with every frame read, see [`PvRecorderBuilder::on_frame`].
+pub type FrameObserver = Arc<dyn Fn(&[i16]) + Send + Sync>;
+
+/// Result of a frame read on a helper thread and the instant the library returned it.
+type PendingRead = mpsc::Receiver<Result<(Vec<i16>, Instant), PvRecorderError>>;
+
 const DEFAULT_DEVICE_INDEX: i32 = -1;
 const DEFAULT_FRAME_LENGTH: i32 = 512;
 const DEFAULT_BUFFERED_FRAMES_COUNT: i32 = 50;
+const CHANNELS: usize = 1;
+const MAX_NORMALIZE_GAIN: f32 = 10.0;
+const EXPECTED_SAMPLE_RATE: usize = 16000;
+const READ_RATE_WINDOW: usize = 32;
+const DEFAULT_STALL_FRAMES: usize = 8;
+
+static SAMPLE_RATE_WARNED: AtomicBool = AtomicBool::new(false);
+const PULSE_APPLICATION_NAME_VAR: &str = "PULSE_PROP_application.name";
+
+const LOW_LATENCY_FRAME_LENGTH: i32 = 256;
+const LOW_LATENCY_BUFFERED_FRAMES_COUNT: i32 = 10;
+const HIGH_STABILITY_FRAME_LENGTH: i32 = 1024;
+const HIGH_STABILITY_BUFFERED_FRAMES_COUNT: i32 = 100;
 
 /// Builder for creating [`PvRecorder`] instances.
 ///
@@ -143,11 +193,33 @@ const DEFAULT_BUFFERED_FRAMES_COUNT: i32 = 50;
 /// let samples = recorder.read().expect("Failed to read samples");
 /// recorder.stop().expect("Failed to stop recording");
 /// ```
+#[allow(clippy::struct_excessive_bools)]
 pub struct PvRecorderBuilder {
     frame_length: i32,
+    frame_duration: Option<Duration>,
     device_index: i32,
     buffered_frames_count: i32,
     library_path: PathBuf,
+    library_candidates: Vec<PathBuf>,
+    arm_machine: Option<String>,
+    platform_override: Option<String>,
+    auto_recover_retries: u32,
+    io_retry_count: u32,
+    io_retry_backoff: Duration,
+    oversized_device_index: Option<usize>,
+    clamp_device_index: bool,
+    device_selector: Option<DeviceSelector>,
+    downmix_to_mono: bool,
+    expected_sample_rate: Option<usize>,
+    normalize_gain: Option<f32>,
+    application_name: Option<String>,
+    auto_start: bool,
+    silence_library_stderr: bool,
+    strict_single_thread: bool,
+    stall_frames: usize,
+    buffer_check_policy: BufferCheckPolicy,
+    exclusive_mode: bool,
+    on_frame: Option<FrameObserver>,
 }
 
 impl Default for PvRecorderBuilder {
@@ -165,9 +237,30 @@ impl PvRecorderBuilder {
     pub fn new(frame_length: i32) -> Self {
         Self {
             frame_length,
+            frame_duration: None,
             device_index: DEFAULT_DEVICE_INDEX,
             buffered_frames_count: DEFAULT_BUFFERED_FRAMES_COUNT,
             library_path: pv_library_path(),
+            library_candidates: Vec::new(),
+            arm_machine: None,
+            platform_override: None,
+            auto_recover_retries: 0,
+            io_retry_count: 0,
+            io_retry_backoff: Duration::ZERO,
+            oversized_device_index: None,
+            clamp_device_index: false,
+            device_selector: None,
+            downmix_to_mono: false,
+            expected_sample_rate: None,
+            normalize_gain: None,
+            application_name: None,
+            auto_start: false,
+            silence_library_stderr: false,
+            strict_single_thread: false,
+            stall_frames: DEFAULT_STALL_FRAMES,
+            buffer_check_policy: BufferCheckPolicy::Panic,
+            exclusive_mode: false,
+            on_frame: None,
         }
     }
 
@@ -176,9 +269,31 @@ impl PvRecorderBuilder {
     #[must_use]
     pub fn frame_length(mut self, frame_length: i32) -> Self {
         self.frame_length = frame_length;
+        self.frame_duration = None;
+        self
+    }
+
+    /// Sets the frame length as a duration, e.g. 20 ms.
+    ///
+    /// The duration is converted to samples at the library's fixed sample rate,
+    /// rounded to the nearest sample, when [`init`](Self::init) loads the library; at
+    /// 16 kHz, 20 ms becomes 320 samples. A later [`frame_length`](Self::frame_length)
+    /// call (including through a preset) replaces it.
+    #[must_use]
+    pub fn frame_duration(mut self, duration: Duration) -> Self {
+        self.frame_duration = Some(duration);
         self
     }
 
+    /// Sets the frame length, validating it immediately.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if `frame_length` is not greater than 0.
+    pub fn try_frame_length(self, frame_length: i32) -> Result<Self, PvRecorderError> {
+        validate_frame_length(frame_length)?;
+        Ok(self.frame_length(frame_length))
+    }
+
     /// Sets the audio device index.
     ///
     /// Use -1 (default) for the system default device, or a specific index
@@ -186,6 +301,71 @@ impl PvRecorderBuilder {
     #[must_use]
     pub fn device_index(mut self, device_index: i32) -> Self {
         self.device_index = device_index;
+        self.oversized_device_index = None;
+        self
+    }
+
+    /// Sets the audio device index, validating it immediately.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if `device_index` is less than -1.
+    pub fn try_device_index(self, device_index: i32) -> Result<Self, PvRecorderError> {
+        validate_device_index(device_index)?;
+        Ok(self.device_index(device_index))
+    }
+
+    /// Selects the audio device, with `None` meaning the system default.
+    ///
+    /// This is an alternative to [`device_index`](Self::device_index) without the
+    /// -1 sentinel. An index that does not fit in an `i32` is rejected by
+    /// [`init`](Self::init).
+    #[must_use]
+    pub fn device(mut self, device: Option<usize>) -> Self {
+        self.oversized_device_index = None;
+        match device {
+            None => self.device_index = DEFAULT_DEVICE_INDEX,
+            Some(index) => match i32::try_from(index) {
+                Ok(device_index) => self.device_index = device_index,
+                Err(_) => self.oversized_device_index = Some(index),
+            },
+        }
+        self
+    }
+
+    /// Falls back to the system default device when `device_index` is out of range.
+    ///
+    /// By default, [`init`](Self::init) rejects an index past the last available
+    /// device with an `ArgumentError` naming the valid range. With `clamp` set, such an
+    /// index is replaced by -1 and a warning is emitted instead (see
+    /// [`set_warning_handler`](crate::set_warning_handler)).
+    #[must_use]
+    pub fn clamp_device_index(mut self, clamp: bool) -> Self {
+        self.clamp_device_index = clamp;
+        self
+    }
+
+    /// Selects the first device for which `predicate` returns `true`.
+    ///
+    /// The devices are enumerated when [`init`](Self::init) is called, and the match
+    /// takes precedence over [`device_index`](Self::device_index). To keep `init` from
+    /// opening an extra device, the default device is not probed, so `is_default` is
+    /// always `false` here; leave the index at -1 to record from the default device.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use pv_recorder::PvRecorderBuilder;
+    ///
+    /// let recorder = PvRecorderBuilder::default()
+    ///     .select_device(|device| !device.name.contains("Monitor"))
+    ///     .init()?;
+    /// # Ok::<(), pv_recorder::PvRecorderError>(())
+    /// ```
+    #[must_use]
+    pub fn select_device<F: Fn(&AudioDevice) -> bool + Send + Sync + 'static>(
+        mut self,
+        predicate: F,
+    ) -> Self {
+        self.device_selector = Some(Box::new(predicate));
         self
     }
 
@@ -196,61 +376,501 @@ impl PvRecorderBuilder {
         self
     }
 
+    /// Sizes the internal buffer as a multiple of the frame length.
+    ///
+    /// The library buffers `n * frame_length` samples in total, i.e. `n` frames; this
+    /// is the same setting as [`buffered_frames_count`](Self::buffered_frames_count),
+    /// named for that relationship. At 16 kHz with 512-sample frames, `n = 50` buffers
+    /// 25,600 samples, or 1.6 s. [`PvRecorder::buffered_samples`] reports the total.
+    #[must_use]
+    pub fn buffer_frames_multiple(self, n: i32) -> Self {
+        self.buffered_frames_count(n)
+    }
+
+    /// Sets the number of frames to buffer internally, validating it immediately.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if `buffered_frames_count` is not greater than 0.
+    pub fn try_buffered_frames_count(
+        self,
+        buffered_frames_count: i32,
+    ) -> Result<Self, PvRecorderError> {
+        validate_buffered_frames_count(buffered_frames_count)?;
+        Ok(self.buffered_frames_count(buffered_frames_count))
+    }
+
+    /// Applies a preset tuned for low latency.
+    ///
+    /// Sets `frame_length` to 256 samples and `buffered_frames_count` to 10, i.e.
+    /// 16 ms frames and a 160 ms internal buffer at 16 kHz. Frames arrive sooner,
+    /// but a reader that stalls for more than the buffer length will overflow and
+    /// lose audio.
+    #[must_use]
+    pub fn low_latency(self) -> Self {
+        self.frame_length(LOW_LATENCY_FRAME_LENGTH)
+            .buffered_frames_count(LOW_LATENCY_BUFFERED_FRAMES_COUNT)
+    }
+
+    /// Applies a preset tuned for robustness against slow readers.
+    ///
+    /// Sets `frame_length` to 1024 samples and `buffered_frames_count` to 100, i.e.
+    /// 64 ms frames and a 6.4 s internal buffer at 16 kHz. The reader can fall behind
+    /// for several seconds without losing audio, at the cost of higher latency per
+    /// frame.
+    #[must_use]
+    pub fn high_stability(self) -> Self {
+        self.frame_length(HIGH_STABILITY_FRAME_LENGTH)
+            .buffered_frames_count(HIGH_STABILITY_BUFFERED_FRAMES_COUNT)
+    }
+
     /// Sets a custom path to the pvrecorder dynamic library.
     #[must_use]
     pub fn library_path(mut self, library_path: &Path) -> Self {
         self.library_path = library_path.into();
+        self.library_candidates.clear();
+        self.arm_machine = None;
+        self.platform_override = None;
+        self
+    }
+
+    /// Sets several candidate paths to the pvrecorder library, tried in order.
+    ///
+    /// Each path is opened in turn and the first that loads is used, e.g. a bundled
+    /// copy followed by system directories. The chosen path is reported by
+    /// [`PvRecorder::library_path`]. If none loads, [`init`](Self::init) fails with a
+    /// `LibraryLoadError` listing every path's failure; an empty list keeps the single
+    /// `library_path`. Like
+    /// [`library_path`](Self::library_path), this replaces `arm_machine` and
+    /// `platform_override`.
+    #[must_use]
+    pub fn library_paths(mut self, library_paths: &[PathBuf]) -> Self {
+        self.library_candidates = library_paths.to_vec();
+        self.arm_machine = None;
+        self.platform_override = None;
+        self
+    }
+
+    /// Loads the host's library from `library_dir`, a relocated copy of the bundled
+    /// `lib/` tree.
+    ///
+    /// The platform-specific relative path (e.g. `linux/x86_64/libpv_recorder.so`) is
+    /// joined onto `library_dir`, the same way the default path is composed under
+    /// `OUT_DIR`. This is equivalent to calling [`library_path`](Self::library_path)
+    /// with the joined path.
+    #[must_use]
+    pub fn library_dir(self, library_dir: &Path) -> Self {
+        let library_path = pv_library_path_in(library_dir);
+        self.library_path(&library_path)
+    }
+
+    /// Selects the bundled Raspberry Pi library for `machine` instead of detecting it
+    /// from `/proc/cpuinfo`.
+    ///
+    /// `machine` is one of `arm11`, `cortex-a53`, `cortex-a72` or `cortex-a76`; the
+    /// 64-bit variant is used when compiled for aarch64. This lets custom boards with a
+    /// known core load the optimized library instead of the armv6 fallback. An unknown
+    /// machine makes [`init`](Self::init) fail with an `ArgumentError`. Whichever of
+    /// `arm_machine`, [`platform_override`](Self::platform_override) and
+    /// [`library_path`](Self::library_path) is called last wins.
+    #[must_use]
+    pub fn arm_machine(mut self, machine: &str) -> Self {
+        self.arm_machine = Some(machine.to_owned());
+        self.platform_override = None;
+        self.library_candidates.clear();
+        self
+    }
+
+    /// Loads the bundled library for `platform` regardless of the host.
+    ///
+    /// `platform` is a directory under `data/lib`, as returned by
+    /// [`PvRecorder::platform`]: e.g. `linux/x86_64`, `mac/arm64`, `windows/amd64` or
+    /// `raspberry-pi/cortex-a72-aarch64`. This is meant for cross-testing, such as loading
+    /// a Raspberry Pi build under QEMU on an x86 machine. A platform without a bundled
+    /// library makes [`init`](Self::init) fail with an `ArgumentError`. Whichever of
+    /// `platform_override`, [`arm_machine`](Self::arm_machine) and
+    /// [`library_path`](Self::library_path) is called last wins.
+    #[must_use]
+    pub fn platform_override(mut self, platform: &str) -> Self {
+        self.platform_override = Some(platform.to_owned());
+        self.arm_machine = None;
+        self.library_candidates.clear();
+        self
+    }
+
+    /// Enables automatic recovery from transient read errors.
+    ///
+    /// When a read fails with `BACKEND_ERROR` or `IO_ERROR`, the recorder stops,
+    /// reinitializes the device with the same settings, restarts and retries the read,
+    /// up to `max_retries` times before returning the error. Other status codes are
+    /// returned immediately. The default of 0 disables recovery.
+    #[must_use]
+    pub fn auto_recover(mut self, max_retries: u32) -> Self {
+        self.auto_recover_retries = max_retries;
+        self
+    }
+
+    /// Retries reads that fail with `IO_ERROR` after a pause, without reinitializing.
+    ///
+    /// Some USB devices report a transient `IO_ERROR` that clears after a short wait.
+    /// With this set, a read failing with `IO_ERROR` sleeps for `backoff` and retries,
+    /// up to `count` times, before falling back to [`auto_recover`](Self::auto_recover)
+    /// or returning the error. This only adds latency to reads that fail. The default
+    /// `count` of 0 disables retrying.
+    #[must_use]
+    pub fn io_retry(mut self, count: u32, backoff: Duration) -> Self {
+        self.io_retry_count = count;
+        self.io_retry_backoff = backoff;
+        self
+    }
+
+    /// Silences the process's stderr while the library initializes and records.
+    ///
+    /// Some library builds print backend messages to stderr even with debug logging
+    /// off. When enabled, file descriptor 2 is redirected to `/dev/null` (`NUL` on
+    /// Windows) during [`init`](Self::init) and from [`PvRecorder::start`] until
+    /// [`PvRecorder::stop`], then restored.
+    ///
+    /// This is best-effort: the redirection is process-wide, so it also hides this
+    /// crate's own stderr warnings and anything else the process prints meanwhile, and
+    /// on Windows it only affects libraries sharing the same C runtime.
+    #[must_use]
+    pub fn silence_library_stderr(mut self, silence: bool) -> Self {
+        self.silence_library_stderr = silence;
+        self
+    }
+
+    /// Requests exclusive access to the device, for lower latency on backends such as
+    /// WASAPI.
+    ///
+    /// The native library opens every backend in shared mode and has no parameter or
+    /// environment variable to change that, so no backend currently honors this.
+    /// Rather than silently recording in shared mode, [`init`](Self::init) fails with an
+    /// `ArgumentError` when it is enabled.
+    #[must_use]
+    pub fn exclusive_mode(mut self, exclusive: bool) -> Self {
+        self.exclusive_mode = exclusive;
+        self
+    }
+
+    /// Sets an observer called with every frame the recorder reads, e.g. for metering.
+    ///
+    /// It runs at the end of each [`PvRecorder::read`], [`PvRecorder::read_into`] and
+    /// every other read method, on the reading thread and after any normalization, so it
+    /// should be fast: a slow observer delays the next read and can overflow the
+    /// library's buffer.
+    #[must_use]
+    pub fn on_frame(mut self, observer: FrameObserver) -> Self {
+        self.on_frame = Some(observer);
+        self
+    }
+
+    /// Chooses whether [`PvRecorder::read_into`] panics (the default) or returns an
+    /// error when its buffer is shorter than `frame_length`.
+    #[must_use]
+    pub fn buffer_check_policy(mut self, policy: BufferCheckPolicy) -> Self {
+        self.buffer_check_policy = policy;
+        self
+    }
+
+    /// Sets how many consecutive identical frames make [`PvRecorder::is_stalled`] report
+    /// a stalled device. Must be at least 2; the default is 8.
+    #[must_use]
+    pub fn stall_frames(mut self, frames: usize) -> Self {
+        self.stall_frames = frames;
+        self
+    }
+
+    /// Makes reads fail unless they come from the thread that called [`PvRecorder::start`].
+    ///
+    /// Reads are meant to come from a single thread. With this enabled, every read from
+    /// a different thread (or before the first start) returns an `OtherError` instead of
+    /// competing for frames. See [`PvRecorder::started_on_current_thread`] to check
+    /// without enforcing it.
+    #[must_use]
+    pub fn strict_single_thread(mut self, strict: bool) -> Self {
+        self.strict_single_thread = strict;
+        self
+    }
+
+    /// Makes [`PvRecorder::read`] average interleaved channels into one mono frame.
+    ///
+    /// Channels are averaged as described in [`analysis::downmix_to_mono`], yielding
+    /// `frame_length` samples per read. The library currently captures mono audio only,
+    /// so this option has no effect until multichannel capture exists: frames are
+    /// returned unchanged either way.
+    #[must_use]
+    pub fn downmix_to_mono(mut self, downmix_to_mono: bool) -> Self {
+        self.downmix_to_mono = downmix_to_mono;
+        self
+    }
+
+    /// Scales each frame read so that its peak approaches `target_peak`.
+    ///
+    /// `target_peak` is a fraction of full scale in `(0.0, 1.0]`. For every frame the
+    /// gain `target_peak * 32767 / peak` is applied, capped at 10x (20 dB) so that
+    /// near-silent frames are not amplified into noise; samples saturate at the `i16`
+    /// range. This is a simple per-frame normalizer, not a proper AGC: the gain can
+    /// change abruptly between frames. The applied gain is reported by
+    /// [`PvRecorder::last_gain`].
+    #[must_use]
+    pub fn normalize_gain(mut self, target_peak: f32) -> Self {
+        self.normalize_gain = Some(target_peak);
+        self
+    }
+
+    /// Sets the client name shown for this recorder in the system mixer, best-effort.
+    ///
+    /// The library has no client-name parameter, so the name is passed through the
+    /// `PULSE_PROP_application.name` environment variable, which is set only while the
+    /// device is being opened (including auto-recovery reconnects) and restored after.
+    /// Support depends on the backend the library picked:
+    /// - `PulseAudio`, and `PipeWire` through its `PulseAudio` layer: the name is shown.
+    /// - ALSA, JACK, Core Audio and WASAPI: ignored.
+    ///
+    /// Changing the environment is not synchronized with other threads reading it, so
+    /// avoid initializing recorders with this option while other threads call
+    /// `getenv`-based code.
+    #[must_use]
+    pub fn application_name(mut self, name: &str) -> Self {
+        self.application_name = Some(name.to_owned());
+        self
+    }
+
+    /// Starts recording before [`init`](Self::init) returns.
+    ///
+    /// Off by default. When enabled and starting fails, `init` returns the start error
+    /// and the device is released.
+    #[must_use]
+    pub fn auto_start(mut self, auto_start: bool) -> Self {
+        self.auto_start = auto_start;
+        self
+    }
+
+    /// Requires the library to record at `sample_rate` Hz.
+    ///
+    /// The sample rate is fixed by the pvrecorder library, so this does not change it;
+    /// instead [`init`](Self::init) fails if the library's rate differs, before any
+    /// wrongly-timed audio is processed.
+    #[must_use]
+    pub fn expected_sample_rate(mut self, sample_rate: usize) -> Self {
+        self.expected_sample_rate = Some(sample_rate);
         self
     }
 
     /// Initializes and returns a new [`PvRecorder`] instance.
     ///
+    /// The device is opened but not started, so no audio is buffered until
+    /// [`PvRecorder::start`] is called, unless [`auto_start`](Self::auto_start) is set.
+    ///
     /// # Errors
     /// Returns an error if:
     /// - `frame_length` is not greater than 0
-    /// - `device_index` is less than -1
+    /// - `device_index` is less than -1, or the index given to `device` exceeds `i32::MAX`
+    /// - `device_index` is past the last available device, unless `clamp_device_index` is set
     /// - `buffered_frames_count` is not greater than 0
+    /// - `normalize_gain` is not in `(0.0, 1.0]`
+    /// - No device matches the `select_device` predicate
     /// - The library fails to load
     /// - The device fails to initialize
+    /// - The library's sample rate differs from `expected_sample_rate`
+    /// - Starting fails with `auto_start` set
     pub fn init(&self) -> Result<PvRecorder, PvRecorderError> {
-        // FIX: Corrected error message - was "greater than or equal to 0"
-        if self.frame_length <= 0 {
+        self.validate()?;
+
+        let device_index = match &self.device_selector {
+            Some(selector) => {
+                PvRecorderInner::get_audio_devices(&self.resolve_library()?.0, false)?
+                    .into_iter()
+                    .find(|device| selector(device))
+                    .map(|device| device.index)
+                    .ok_or_else(|| {
+                        PvRecorderError::new(
+                            PvRecorderErrorStatus::ArgumentError,
+                            "no audio device matches the select_device predicate",
+                        )
+                    })?
+            }
+            None => self.check_device_index(self.device_index)?,
+        };
+
+        self.open(device_index)
+    }
+
+    /// Initializes a recorder on the first listed device that opens successfully.
+    ///
+    /// Devices are tried in index order with this builder's configuration, ignoring
+    /// `device_index` and `select_device`. This is a robust entry point for headless
+    /// machines where the default device may fail while a specific one works.
+    ///
+    /// # Errors
+    /// Returns the same configuration errors as [`init`](Self::init). If no device
+    /// opens, returns an `OtherError` listing each device's failure, or stating that
+    /// no device is available.
+    pub fn init_first_working(&self) -> Result<PvRecorder, PvRecorderError> {
+        self.validate()?;
+
+        let mut failures = Vec::new();
+        let (library_path, _) = self.resolve_library()?;
+        for device in PvRecorderInner::get_audio_devices(&library_path, false)? {
+            match self.open(device.index) {
+                Ok(recorder) => return Ok(recorder),
+                Err(err) => failures.push(format!("{} ({}): {err}", device.index, device.name)),
+            }
+        }
+
+        let message = if failures.is_empty() {
+            String::from("no audio input devices available")
+        } else {
+            format!("no audio device could be opened: {}", failures.join("; "))
+        };
+        Err(PvRecorderError::new(
+            PvRecorderErrorStatus::OtherError,
+            message,
+        ))
+    }
+
+    /// Initializes a recorder on `device_index` with this builder's other settings,
+    /// ignoring its own `device_index` and `select_device`.
+    pub(crate) fn init_device(&self, device_index: i32) -> Result<PvRecorder, PvRecorderError> {
+        self.validate()?;
+        validate_device_index(device_index)?;
+        let device_index = self.check_device_index(device_index)?;
+        self.open(device_index)
+    }
+
+    /// Returns the frame length in samples, converting `frame_duration` if set.
+    fn resolve_frame_length(&self, library_path: &Path) -> Result<i32, PvRecorderError> {
+        let Some(duration) = self.frame_duration else {
+            return Ok(self.frame_length);
+        };
+
+        let sample_rate = PvRecorderInner::library_sample_rate(library_path)?;
+        let samples = duration_to_samples(duration, sample_rate);
+        if samples == 0 {
             return Err(PvRecorderError::new(
                 PvRecorderErrorStatus::ArgumentError,
                 format!(
-                    "frame_length must be greater than 0, got: {}",
-                    self.frame_length
+                    "frame_duration {duration:?} is shorter than one sample at {sample_rate} Hz"
                 ),
             ));
         }
+        i32::try_from(samples).map_err(|_| {
+            PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!("frame_duration {duration:?} is too long: {samples} samples"),
+            )
+        })
+    }
+
+    /// Validates the configuration that does not depend on the chosen device.
+    fn validate(&self) -> Result<(), PvRecorderError> {
+        if self.frame_duration.is_none() {
+            validate_frame_length(self.frame_length)?;
+        }
 
-        if self.device_index < -1 {
+        if let Some(device_index) = self.oversized_device_index {
             return Err(PvRecorderError::new(
                 PvRecorderErrorStatus::ArgumentError,
-                format!(
-                    "device_index must be >= -1, got: {}",
-                    self.device_index
-                ),
+                format!("device_index must fit in an i32, got: {device_index}"),
+            ));
+        }
+
+        validate_device_index(self.device_index)?;
+        validate_buffered_frames_count(self.buffered_frames_count)?;
+
+        if self.exclusive_mode {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                "exclusive_mode is not supported by the pvrecorder library",
             ));
         }
 
-        if self.buffered_frames_count <= 0 {
+        if self.stall_frames < 2 {
             return Err(PvRecorderError::new(
                 PvRecorderErrorStatus::ArgumentError,
                 format!(
-                    "buffered_frames_count must be greater than 0, got: {}",
-                    self.buffered_frames_count
+                    "stall_frames must be at least 2, got: {}",
+                    self.stall_frames
                 ),
             ));
         }
 
-        let recorder_inner = PvRecorderInner::init(
-            self.frame_length,
-            self.device_index,
+        if let Some(target_peak) = self.normalize_gain {
+            if !(target_peak > 0.0 && target_peak <= 1.0) {
+                return Err(PvRecorderError::new(
+                    PvRecorderErrorStatus::ArgumentError,
+                    format!("normalize_gain target must be in (0.0, 1.0], got: {target_peak}"),
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Collects the options that are applied on the Rust side of every read.
+    fn options(&self) -> RecorderOptions {
+        RecorderOptions {
+            auto_recover_retries: self.auto_recover_retries,
+            io_retry_count: self.io_retry_count,
+            io_retry_backoff: self.io_retry_backoff,
+            silence_stderr: self.silence_library_stderr,
+            strict_single_thread: self.strict_single_thread,
+            stall_frames: self.stall_frames,
+            buffer_check_policy: self.buffer_check_policy,
+            on_frame: self.on_frame.clone(),
+            downmix_to_mono: self.downmix_to_mono,
+            normalize_gain: self.normalize_gain,
+        }
+    }
+
+    /// Opens the recorder on `device_index` and applies the Rust-side options.
+    fn open(&self, device_index: i32) -> Result<PvRecorder, PvRecorderError> {
+        let (library_path, platform) = self.resolve_library()?;
+        let stderr_guard = self
+            .silence_library_stderr
+            .then(StderrGuard::acquire)
+            .flatten();
+        let frame_length = self.resolve_frame_length(&library_path)?;
+        let mut inner = PvRecorderInner::init(
+            frame_length,
+            device_index,
             self.buffered_frames_count,
-            &self.library_path,
-        );
-        recorder_inner.map(|inner| PvRecorder {
+            &library_path,
+            self.application_name.as_deref(),
+        )?;
+        drop(stderr_guard);
+
+        if inner.sample_rate() != EXPECTED_SAMPLE_RATE
+            && !SAMPLE_RATE_WARNED.swap(true, Ordering::Relaxed)
+        {
+            warn(&format!(
+                "pvrecorder library records at {} Hz instead of the usual {EXPECTED_SAMPLE_RATE} Hz. \
+                Use sample_rate() rather than assuming {EXPECTED_SAMPLE_RATE} Hz.",
+                inner.sample_rate()
+            ));
+        }
+
+        if let Some(expected_sample_rate) = self.expected_sample_rate {
+            if inner.sample_rate() != expected_sample_rate {
+                return Err(PvRecorderError::new(
+                    PvRecorderErrorStatus::ArgumentError,
+                    format!(
+                        "expected sample rate {expected_sample_rate} Hz, but the library records at {} Hz",
+                        inner.sample_rate()
+                    ),
+                ));
+            }
+        }
+
+        inner.options = self.options();
+        inner.requested_device_index = self.device_index;
+        inner.resolved_device_index =
+            resolve_device_index(device_index, &inner.selected_device, &library_path);
+        inner.platform = platform.unwrap_or_else(|| pv_platform().to_owned());
+        if self.auto_start {
+            inner.start()?;
+        }
+        Ok(PvRecorder {
             inner: Arc::new(inner),
         })
     }
@@ -259,9 +879,159 @@ impl PvRecorderBuilder {
     ///
     /// The index of each device in the returned vector can be used with
     /// [`device_index`](Self::device_index).
+    ///
+    /// An empty vector means enumeration succeeded but no input device is present.
+    /// Failures to load the library or to enumerate devices are reported as errors,
+    /// so the two cases can be told apart.
     pub fn get_available_devices(&self) -> Result<Vec<String>, PvRecorderError> {
-        PvRecorderInner::get_available_devices(&self.library_path)
+        let (library_path, _) = self.resolve_library()?;
+        PvRecorderInner::get_available_devices(library_path)
+    }
+
+    /// Lists the available devices like [`get_available_devices`](Self::get_available_devices),
+    /// retrying transient failures.
+    ///
+    /// Enumeration can briefly fail with `BACKEND_ERROR` or `IO_ERROR` right after
+    /// another process releases the audio backend. Such failures are retried after
+    /// sleeping for `delay`, for up to `attempts` attempts in total (at least one);
+    /// other errors are returned immediately.
+    ///
+    /// # Errors
+    /// Returns the last error if every attempt fails, or the first non-transient error.
+    pub fn get_available_devices_retry(
+        &self,
+        attempts: u32,
+        delay: Duration,
+    ) -> Result<Vec<String>, PvRecorderError> {
+        let mut attempt = 1;
+        loop {
+            match self.get_available_devices() {
+                Err(err)
+                    if attempt < attempts
+                        && matches!(
+                            err.status(),
+                            PvRecorderErrorStatus::LibraryError(status) if is_recoverable_status(*status)
+                        ) =>
+                {
+                    attempt += 1;
+                    thread::sleep(delay);
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Checks `device_index` against the available devices, applying `clamp_device_index`.
+    fn check_device_index(&self, device_index: i32) -> Result<i32, PvRecorderError> {
+        if device_index == DEFAULT_DEVICE_INDEX {
+            return Ok(device_index);
+        }
+
+        let count = self.get_available_devices()?.len();
+        if usize::try_from(device_index).is_ok_and(|index| index < count) {
+            return Ok(device_index);
+        }
+
+        if self.clamp_device_index {
+            warn(&format!(
+                "device_index {device_index} is out of range (0..{count}), using the default device."
+            ));
+            return Ok(DEFAULT_DEVICE_INDEX);
+        }
+        Err(PvRecorderError::new(
+            PvRecorderErrorStatus::ArgumentError,
+            format!(
+                "device_index {device_index} is out of range, valid indices are 0..{count} or -1 for the default device"
+            ),
+        ))
+    }
+
+    /// Returns the library to load, and its platform string if `arm_machine` or
+    /// `platform_override` selected it.
+    fn resolve_library(&self) -> Result<(PathBuf, Option<String>), PvRecorderError> {
+        if let Some(machine) = &self.arm_machine {
+            let (library_path, platform) = pv_arm_library(machine)?;
+            return Ok((library_path, Some(platform)));
+        }
+        if let Some(platform) = &self.platform_override {
+            let library_path = pv_platform_library(platform)?;
+            return Ok((library_path, Some(platform.clone())));
+        }
+        if !self.library_candidates.is_empty() {
+            return first_loadable_library(&self.library_candidates).map(|path| (path, None));
+        }
+        Ok((self.library_path.clone(), None))
     }
+
+    /// Returns the available audio input devices together with their indices.
+    ///
+    /// The library does not report which device is the default, so it is found by
+    /// briefly opening a bare handle on the default device (index -1), with the library
+    /// loaded once for both steps, and matching its name against the list; the first
+    /// match gets `is_default` set. If the default device cannot be opened, no device
+    /// is marked.
+    pub fn get_audio_devices(&self) -> Result<Vec<AudioDevice>, PvRecorderError> {
+        let (library_path, _) = self.resolve_library()?;
+        PvRecorderInner::get_audio_devices(&library_path, true)
+    }
+
+    /// Returns the native period size of the device at `device_index`, if the library
+    /// reports one.
+    ///
+    /// The bundled pvrecorder library does not expose device period sizes, so this
+    /// currently always returns `None`; callers should fall back to their own
+    /// `frame_length` choice (512 by default).
+    #[must_use]
+    #[allow(clippy::unused_self)]
+    pub fn preferred_frame_length(&self, device_index: i32) -> Option<usize> {
+        let _ = device_index;
+        None
+    }
+}
+
+/// Maps the default device index (-1) to the index of the listed device named
+/// `selected_device`, leaving other indices unchanged.
+fn resolve_device_index(device_index: i32, selected_device: &str, library_path: &Path) -> i32 {
+    if device_index != DEFAULT_DEVICE_INDEX {
+        return device_index;
+    }
+    PvRecorderInner::get_available_devices(library_path)
+        .unwrap_or_default()
+        .iter()
+        .position(|name| name == selected_device)
+        .and_then(|index| i32::try_from(index).ok())
+        .unwrap_or(DEFAULT_DEVICE_INDEX)
+}
+
+fn validate_frame_length(frame_length: i32) -> Result<(), PvRecorderError> {
+    // FIX: Corrected error message - was "greater than or equal to 0"
+    if frame_length <= 0 {
+        return Err(PvRecorderError::new(
+            PvRecorderErrorStatus::ArgumentError,
+            format!("frame_length must be greater than 0, got: {frame_length}"),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_device_index(device_index: i32) -> Result<(), PvRecorderError> {
+    if device_index < -1 {
+        return Err(PvRecorderError::new(
+            PvRecorderErrorStatus::ArgumentError,
+            format!("device_index must be >= -1, got: {device_index}"),
+        ));
+    }
+    Ok(())
+}
+
+fn validate_buffered_frames_count(buffered_frames_count: i32) -> Result<(), PvRecorderError> {
+    if buffered_frames_count <= 0 {
+        return Err(PvRecorderError::new(
+            PvRecorderErrorStatus::ArgumentError,
+            format!("buffered_frames_count must be greater than 0, got: {buffered_frames_count}"),
+        ));
+    }
+    Ok(())
 }
 
 /// Audio recorder for capturing microphone input.
@@ -296,6 +1066,7 @@ impl std::fmt::Debug for PvRecorder {
         f.debug_struct("PvRecorder")
             .field("frame_length", &self.frame_length())
             .field("sample_rate", &self.sample_rate())
+            .field("channels", &self.channels())
             .field("selected_device", &self.selected_device())
             .field("version", &self.version())
             .field("is_recording", &self.is_recording())
@@ -303,6 +1074,29 @@ impl std::fmt::Debug for PvRecorder {
     }
 }
 
+impl std::fmt::Display for PvRecorder {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} ({} Hz, {} channel(s), {} samples per frame)",
+            self.selected_device(),
+            self.sample_rate(),
+            self.channels(),
+            self.frame_length()
+        )
+    }
+}
+
+/// Two recorders are equal if they share the same underlying handle, i.e. one is a
+/// clone of the other. Use [`PvRecorder::same_config`] to compare configurations.
+impl PartialEq for PvRecorder {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for PvRecorder {}
+
 impl PvRecorder {
     /// Starts recording audio from the selected device.
     ///
@@ -320,12 +1114,126 @@ impl PvRecorder {
         self.inner.stop()
     }
 
-    /// Reads one frame of audio samples.
+    /// Opens the device at `new_index` with this recorder's configuration, starts it and
+    /// returns the new recorder.
     ///
-    /// This method blocks until a full frame is available.
+    /// The new recorder uses the same library, frame length, buffer size, debug logging
+    /// and every read option set on the builder (retries, stall detection, observers,
+    /// downmixing, gain normalization, and so on). This
+    /// recorder is left untouched and keeps recording if it was: the caller swaps its
+    /// references to the returned recorder and then stops this one, so no audio is
+    /// missed during a device failover. Counters start from zero on the new recorder.
     ///
-    /// # Returns
-    /// A vector of `i16` samples with length equal to [`frame_length`](Self::frame_length).
+    /// # Errors
+    /// Returns an error if `new_index` is out of range, or the new device fails to
+    /// initialize or start.
+    pub fn switch_device(&self, new_index: i32) -> Result<PvRecorder, PvRecorderError> {
+        validate_device_index(new_index)?;
+        let device_index = PvRecorderBuilder::default()
+            .library_path(&self.inner.library_path)
+            .check_device_index(new_index)?;
+
+        let frame_length = i32::try_from(self.inner.frame_length).unwrap_or(i32::MAX);
+        let stderr_guard = self
+            .inner
+            .options
+            .silence_stderr
+            .then(StderrGuard::acquire)
+            .flatten();
+        let mut inner = PvRecorderInner::init(
+            frame_length,
+            device_index,
+            self.inner.buffered_frames_count,
+            &self.inner.library_path,
+            self.inner.application_name.as_deref(),
+        )?;
+        drop(stderr_guard);
+        inner.options = self.inner.options.clone();
+        inner.platform.clone_from(&self.inner.platform);
+        inner.requested_device_index = new_index;
+        inner.resolved_device_index = resolve_device_index(
+            device_index,
+            &inner.selected_device,
+            &self.inner.library_path,
+        );
+        if self.is_debug_logging() {
+            inner.set_debug_logging(true);
+        }
+
+        let recorder = PvRecorder {
+            inner: Arc::new(inner),
+        };
+        recorder.start()?;
+        Ok(recorder)
+    }
+
+    /// Stops recording if the recorder is recording, and does nothing otherwise.
+    ///
+    /// Unlike [`stop`](Self::stop), this does not fail with `INVALID_STATE` when the
+    /// recorder was never started, which suits cleanup paths.
+    ///
+    /// # Errors
+    /// Returns an error if stopping a recording recorder fails.
+    pub fn stop_if_recording(&self) -> Result<(), PvRecorderError> {
+        if self.is_recording() {
+            self.inner.stop()?;
+        }
+        Ok(())
+    }
+
+    /// Stops recording and returns the audio still buffered on the Rust side.
+    ///
+    /// Draining the library's own buffer would need a buffered-count query, which the
+    /// library does not offer (see [`capabilities`](Self::capabilities)), and it empties
+    /// that buffer when stopped. So the returned samples are only those held back by
+    /// [`read_exact_samples`](Self::read_exact_samples), and are empty otherwise. Read
+    /// the last frames before calling this to keep them.
+    ///
+    /// # Errors
+    /// Returns an error if stopping fails, in which case the samples are kept.
+    pub fn stop_draining(&self) -> Result<Vec<i16>, PvRecorderError> {
+        self.inner.stop()?;
+        let drained = std::mem::take(
+            &mut *self
+                .inner
+                .leftover_samples
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner),
+        );
+        self.inner
+            .last_frame_valid_samples
+            .store(drained.len(), Ordering::Relaxed);
+        Ok(drained)
+    }
+
+    /// Discards audio that accumulated before the next read.
+    ///
+    /// Drops the samples held back by [`read_exact_samples`](Self::read_exact_samples)
+    /// and, while recording, restarts the stream; the library empties its internal buffer
+    /// when stopped. Call this right before the audio of interest, e.g. after a long
+    /// pause between reads, so the next read starts with fresh audio.
+    ///
+    /// # Errors
+    /// Returns an error if restarting the stream fails.
+    pub fn discard_buffered(&self) -> Result<(), PvRecorderError> {
+        self.inner
+            .leftover_samples
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+        if self.is_recording() {
+            self.inner.stop()?;
+            self.inner.start()?;
+        }
+        Ok(())
+    }
+
+    /// Reads one frame of audio samples.
+    ///
+    /// This method blocks until a full frame is available.
+    ///
+    /// # Returns
+    /// A vector of `i16` samples with length equal to [`frame_length`](Self::frame_length).
     ///
     /// # Errors
     /// Returns an error if the recorder is not started or a read error occurs.
@@ -333,52 +1241,1052 @@ impl PvRecorder {
         self.inner.read()
     }
 
+    /// Reads one frame of audio samples into a buffer obtained from `alloc`.
+    ///
+    /// `alloc` is called once with the number of samples needed and must return a
+    /// vector of at least that length, e.g. one taken from a pool; any extra samples are
+    /// truncated. [`read`](Self::read) is equivalent to `read_with_alloc(|n| vec![0; n])`.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if the returned vector is too short, or an error if the
+    /// recorder is not started or a read error occurs.
+    pub fn read_with_alloc<F: FnOnce(usize) -> Vec<i16>>(
+        &self,
+        alloc: F,
+    ) -> Result<Vec<i16>, PvRecorderError> {
+        self.inner.read_with_alloc(alloc)
+    }
+
+    /// Reads one frame as normalized `f64` samples with the frame's DC offset removed.
+    ///
+    /// Samples are scaled to `[-1.0, 1.0)` and the mean of the frame is subtracted, as
+    /// in [`analysis::remove_dc_offset`]. The offset is estimated per frame, not with a
+    /// running high-pass filter.
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs.
+    pub fn read_f64_dc_removed(&self) -> Result<Vec<f64>, PvRecorderError> {
+        let mut samples = analysis::i16_to_f64(&self.inner.read()?);
+        analysis::remove_dc_offset(&mut samples);
+        Ok(samples)
+    }
+
+    /// Reads exactly `n` samples, regardless of frame boundaries.
+    ///
+    /// Whole frames are read as needed; samples beyond `n` from the last frame are kept
+    /// and returned first by the next call. If a read fails, the samples gathered so far
+    /// are kept for the next call as well, so no audio is lost.
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs.
+    pub fn read_exact_samples(&self, n: usize) -> Result<Vec<i16>, PvRecorderError> {
+        self.inner.read_exact_samples(n)
+    }
+
+    /// Reads one frame of audio samples into an exactly sized boxed slice.
+    ///
+    /// Prefer this over [`read`](Self::read) when retaining many frames, as the
+    /// returned allocation carries no spare capacity.
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs.
+    pub fn read_boxed(&self) -> Result<Box<[i16]>, PvRecorderError> {
+        self.inner.read().map(Vec::into_boxed_slice)
+    }
+
+    /// Reads one frame of audio samples and measures how long the read blocked.
+    ///
+    /// The returned [`Duration`] is the wall-clock time spent inside the library's
+    /// read call, which is useful for tuning `buffered_frames_count`.
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs.
+    pub fn read_timed(&self) -> Result<(Vec<i16>, Duration), PvRecorderError> {
+        self.inner.read_timed()
+    }
+
+    /// Reads one frame and returns it together with a copy resampled to `target_rate` Hz.
+    ///
+    /// Both come from the same library read, which makes it easy to compare a
+    /// resampler's output against its input. The copy uses
+    /// [`analysis::resample_linear`], which resamples each frame in isolation.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if `target_rate` is 0, or an error if the recorder is
+    /// not started or a read error occurs.
+    pub fn read_dual(&self, target_rate: usize) -> Result<(Vec<i16>, Vec<i16>), PvRecorderError> {
+        if target_rate == 0 {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                "target_rate must be greater than 0",
+            ));
+        }
+
+        let frame = self.read()?;
+        let resampled = analysis::resample_linear(&frame, self.sample_rate(), target_rate);
+        Ok((frame, resampled))
+    }
+
+    /// Reads one frame of audio samples together with the instant it was captured.
+    ///
+    /// The [`Instant`] is taken as soon as the library's read call returns, before any
+    /// post-processing, so it can anchor audio to the system clock, e.g. for A/V sync.
+    /// Combine it with [`first_frame_instant`](Self::first_frame_instant) for offsets
+    /// relative to the start of the stream.
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs.
+    pub fn read_stamped(&self) -> Result<(Vec<i16>, Instant), PvRecorderError> {
+        let mut frame = vec![0; self.frame_length()];
+        let read_at = self.inner.read_frame_stamped(&mut frame)?;
+        Ok((frame, read_at))
+    }
+
+    /// Records `duration` worth of audio and returns it.
+    ///
+    /// Starts the recorder if it is not already recording, and stops it again afterwards
+    /// only in that case. Whole frames are read until the duration is covered, and the
+    /// result is truncated to exactly `duration * sample_rate` samples (rounded to the
+    /// nearest sample).
+    ///
+    /// # Errors
+    /// Returns an error if starting, reading or stopping fails.
+    pub fn capture(&self, duration: Duration) -> Result<Vec<i16>, PvRecorderError> {
+        self.capture_with_progress(duration, |_| {})
+    }
+
+    /// Records `duration` worth of audio like [`capture`](Self::capture), reporting
+    /// progress after every frame.
+    ///
+    /// `on_progress` is called once per frame read with the amount captured so far,
+    /// so it can drive e.g. a "2.1s of 3.0s captured" progress bar.
+    ///
+    /// # Errors
+    /// Returns an error if starting, reading or stopping fails.
+    pub fn capture_with_progress<F>(
+        &self,
+        duration: Duration,
+        mut on_progress: F,
+    ) -> Result<Vec<i16>, PvRecorderError>
+    where
+        F: FnMut(&CaptureProgress),
+    {
+        let started = !self.is_recording();
+        if started {
+            self.start()?;
+        }
+
+        let sample_rate = self.sample_rate();
+        let target_samples = duration_to_samples(duration, sample_rate);
+        let result = self
+            .inner
+            .read_samples(target_samples, &mut |captured_samples| {
+                on_progress(&CaptureProgress {
+                    captured_samples,
+                    target_samples,
+                    sample_rate,
+                });
+            });
+
+        if started {
+            let stopped = self.stop();
+            return result.and_then(|samples| stopped.map(|()| samples));
+        }
+        result
+    }
+
+    /// Records until the input has been silent for `silence_duration`, or for at most
+    /// `max_duration`, and returns the audio.
+    ///
+    /// A frame is silent when its [`analysis::rms`] level, normalized to `[0.0, 1.0]`,
+    /// is below `silence_threshold`. Silence is counted in whole frames from any point,
+    /// including before the first speech, and the trailing silence is kept in the
+    /// result. The result is truncated to `max_duration` (rounded to the nearest
+    /// sample). Like [`capture`](Self::capture), this starts the recorder if needed and
+    /// then stops it again only in that case.
+    ///
+    /// # Errors
+    /// Returns an error if starting, reading or stopping fails.
+    pub fn capture_until_silence(
+        &self,
+        silence_threshold: f32,
+        silence_duration: Duration,
+        max_duration: Duration,
+    ) -> Result<Vec<i16>, PvRecorderError> {
+        let started = !self.is_recording();
+        if started {
+            self.start()?;
+        }
+
+        let silence_samples = duration_to_samples(silence_duration, self.sample_rate());
+        let max_samples = duration_to_samples(max_duration, self.sample_rate());
+        let mut samples = Vec::new();
+        let mut silent = 0;
+        let mut result = Ok(());
+        while samples.len() < max_samples {
+            let frame = match self.read() {
+                Ok(frame) => frame,
+                Err(err) => {
+                    result = Err(err);
+                    break;
+                }
+            };
+            if analysis::rms(&frame) < silence_threshold {
+                silent += frame.len();
+            } else {
+                silent = 0;
+            }
+            samples.extend_from_slice(&frame);
+            if silent >= silence_samples {
+                break;
+            }
+        }
+        samples.truncate(max_samples);
+
+        if started {
+            let stopped = self.stop();
+            result = result.and(stopped);
+        }
+        result.map(|()| samples)
+    }
+
+    /// Reads frames and passes each one to `on_frame` until `stop` is set or recording
+    /// ends, and returns the number of frames processed.
+    ///
+    /// `stop` is checked before every read, so the loop exits within one frame of it
+    /// being set, e.g. from a Ctrl-C handler. It is taken by reference, so an
+    /// `Arc<AtomicBool>` shared with the handler can be passed as `&flag`. A single
+    /// frame buffer is reused across reads.
+    ///
+    /// # Errors
+    /// Returns the first read error; frames processed before it are not reported.
+    pub fn record_until<F>(
+        &self,
+        stop: &AtomicBool,
+        mut on_frame: F,
+    ) -> Result<usize, PvRecorderError>
+    where
+        F: FnMut(&[i16]),
+    {
+        let mut frame = vec![0; self.frame_length()];
+        let mut frames = 0;
+        while !stop.load(Ordering::Relaxed) && self.is_recording() {
+            self.inner.read_frame(&mut frame)?;
+            on_frame(&frame);
+            frames += 1;
+        }
+        Ok(frames)
+    }
+
+    /// Checks that audio is actually flowing from the device.
+    ///
+    /// While recording, waits up to `timeout` for one frame and returns `true` if it
+    /// arrived and contains at least one non-zero sample. Returns `false` if the
+    /// recorder is not recording, the frame did not arrive in time, or it was all
+    /// zeros, which lets a watchdog detect a stalled or silent device.
+    ///
+    /// The library's read cannot be cancelled, so the frame is read on a helper thread.
+    /// On timeout that read stays pending: the next `health_check` or
+    /// [`read_until`](Self::read_until) waits on it instead of starting another, and
+    /// the next plain read such as [`read`](Self::read) returns its frame, so no audio
+    /// is lost and the handle is never read from two threads at once.
+    ///
+    /// # Errors
+    /// Returns an error if the read fails, or an `OtherError` if the recorder was built
+    /// with [`PvRecorderBuilder::strict_single_thread`], whose reads must stay on the
+    /// starting thread.
+    pub fn health_check(&self, timeout: Duration) -> Result<bool, PvRecorderError> {
+        if !self.is_recording() {
+            return Ok(false);
+        }
+
+        Ok(self
+            .read_with_timeout(timeout)?
+            .is_some_and(|frame| frame.iter().any(|&sample| sample != 0)))
+    }
+
+    /// Reads one frame, giving up at `deadline`.
+    ///
+    /// Returns `Ok(None)` if the frame did not arrive before `deadline`, or
+    /// immediately if `deadline` has already passed. As with
+    /// [`health_check`](Self::health_check), a read that misses the deadline stays
+    /// pending, and its frame is returned by the next `read_until` or plain read.
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs, or an
+    /// `OtherError` under [`PvRecorderBuilder::strict_single_thread`].
+    pub fn read_until(&self, deadline: Instant) -> Result<Option<Vec<i16>>, PvRecorderError> {
+        let now = Instant::now();
+        if deadline <= now {
+            return Ok(None);
+        }
+        self.read_with_timeout(deadline - now)
+    }
+
+    /// Reads one frame on a helper thread, waiting at most `timeout` for it.
+    ///
+    /// At most one helper read is in flight per recorder; one that timed out is waited
+    /// on again here, or handed to the next plain read.
+    fn read_with_timeout(&self, timeout: Duration) -> Result<Option<Vec<i16>>, PvRecorderError> {
+        if self.inner.options.strict_single_thread {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::OtherError,
+                "strict_single_thread: timed reads need a helper thread and are not supported",
+            ));
+        }
+
+        let receiver = self.inner.take_pending_read().unwrap_or_else(|| {
+            let (sender, receiver) = mpsc::channel();
+            let inner = Arc::clone(&self.inner);
+            thread::spawn(move || {
+                let mut frame = vec![0; inner.frame_length()];
+                // SAFETY: `frame` holds `frame_length` samples.
+                let result = unsafe { inner.read_frame_direct(frame.as_mut_ptr()) }
+                    .map(|read_at| (frame, read_at));
+                let _ = sender.send(result);
+            });
+            receiver
+        });
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => result.map(|(frame, _)| Some(frame)),
+            Err(RecvTimeoutError::Timeout) => {
+                *self
+                    .inner
+                    .pending_read
+                    .lock()
+                    .unwrap_or_else(PoisonError::into_inner) = Some(receiver);
+                Ok(None)
+            }
+            Err(RecvTimeoutError::Disconnected) => Err(pending_read_lost()),
+        }
+    }
+
+    /// Reads one frame into per-channel buffers.
+    ///
+    /// `out` must contain exactly [`channels`](Self::channels) vectors; each is resized
+    /// to [`frame_length`](Self::frame_length) and filled with that channel's samples.
+    /// The library captures mono audio, so `out` currently holds a single plane, which
+    /// is read into directly without an intermediate buffer.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if `out.len() != self.channels()`, or an error if
+    /// the recorder is not started or a read error occurs.
+    pub fn read_planar(&self, out: &mut [Vec<i16>]) -> Result<(), PvRecorderError> {
+        if out.len() != self.channels() {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!(
+                    "expected {} channel buffers, got: {}",
+                    self.channels(),
+                    out.len()
+                ),
+            ));
+        }
+
+        let plane = &mut out[0];
+        plane.resize(self.frame_length(), 0);
+        self.inner.read_frame(plane)
+    }
+
     /// Reads audio samples into the provided buffer.
     ///
     /// This is more efficient than [`read`](Self::read) as it avoids allocation.
     ///
+    /// By default this is the panicking convenience variant; use
+    /// [`try_read_into`](Self::try_read_into) to get an error instead, or set
+    /// [`BufferCheckPolicy::Error`] with [`PvRecorderBuilder::buffer_check_policy`].
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs, or an
+    /// `ArgumentError` for an undersized buffer under [`BufferCheckPolicy::Error`].
+    ///
     /// # Panics
-    /// Panics if `buffer.len() < self.frame_length()`.
+    /// Panics if `buffer.len() < self.frame_length()`, under the default
+    /// [`BufferCheckPolicy::Panic`].
     pub fn read_into(&self, buffer: &mut [i16]) -> Result<(), PvRecorderError> {
         self.inner.read_into(buffer)
     }
 
-    /// Enables or disables debug logging.
-    pub fn set_debug_logging(&self, is_debug_logging_enabled: bool) {
-        self.inner.set_debug_logging(is_debug_logging_enabled)
+    /// Reads audio samples into the provided buffer, without panicking.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if `buffer.len() < self.frame_length()`, or an error
+    /// if the recorder is not started or a read error occurs.
+    pub fn try_read_into(&self, buffer: &mut [i16]) -> Result<(), PvRecorderError> {
+        self.inner.try_read_into(buffer)
+    }
+
+    /// Fills `out` with as many whole frames as fit and returns the number of samples
+    /// written.
+    ///
+    /// The count is a multiple of [`frame_length`](Self::frame_length) and at most
+    /// `out.len()`; any tail shorter than a frame is left untouched. Unlike
+    /// [`read_into`](Self::read_into), this reads several frames per call, which suits
+    /// filling one large pre-allocated buffer.
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs. Frames
+    /// read before the error are kept in `out`, but their count is not reported.
+    pub fn read_samples_into(&self, out: &mut [i16]) -> Result<usize, PvRecorderError> {
+        let mut written = 0;
+        for frame in out.chunks_exact_mut(self.frame_length()) {
+            self.inner.try_read_into(frame)?;
+            written += frame.len();
+        }
+        Ok(written)
+    }
+
+    /// Reads one frame into a possibly uninitialized buffer and returns the filled part.
+    ///
+    /// This avoids zeroing the buffer beforehand, as [`read`](Self::read) does. It is
+    /// sound because the library's read writes exactly `frame_length` samples into the
+    /// buffer before reporting success, so the returned `&mut [i16]` of that length is
+    /// fully initialized; on error, nothing is assumed about the buffer.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if `buffer.len() < self.frame_length()`, or an error
+    /// if the recorder is not started or a read error occurs.
+    pub fn read_into_uninit<'a>(
+        &self,
+        buffer: &'a mut [MaybeUninit<i16>],
+    ) -> Result<&'a mut [i16], PvRecorderError> {
+        self.inner.read_into_uninit(buffer)
+    }
+
+    /// Enables or disables debug logging.
+    ///
+    /// See [`try_set_debug_logging`](Self::try_set_debug_logging) for a variant that
+    /// reports a handle in a bad state instead of proceeding.
+    pub fn set_debug_logging(&self, is_debug_logging_enabled: bool) {
+        self.inner.set_debug_logging(is_debug_logging_enabled);
+    }
+
+    /// Enables or disables debug logging, checking the handle first.
+    ///
+    /// The library's call itself cannot fail, so the only error reported is a handle
+    /// left in an unknown state by a panic during auto-recovery.
+    ///
+    /// # Errors
+    /// Returns an `OtherError` if the handle may be in an inconsistent state.
+    pub fn try_set_debug_logging(
+        &self,
+        is_debug_logging_enabled: bool,
+    ) -> Result<(), PvRecorderError> {
+        if self.inner.cpvrecorder.is_poisoned() {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::OtherError,
+                "recorder handle is in an inconsistent state after a failed recovery",
+            ));
+        }
+        self.inner.set_debug_logging(is_debug_logging_enabled);
+        Ok(())
+    }
+
+    /// Returns whether debug logging is enabled.
+    ///
+    /// The C library has no getter for this flag, so this reports the last value
+    /// passed to [`set_debug_logging`](Self::set_debug_logging) (initially `false`).
+    #[must_use]
+    pub fn is_debug_logging(&self) -> bool {
+        self.inner.is_debug_logging.load(Ordering::Relaxed)
+    }
+
+    /// Returns the number of samples per frame.
+    #[must_use]
+    pub fn frame_length(&self) -> usize {
+        self.inner.frame_length()
+    }
+
+    /// Returns `true` if both recorders have the same frame length, sample rate, device
+    /// and library version, even if they are separate handles.
+    #[must_use]
+    pub fn same_config(&self, other: &PvRecorder) -> bool {
+        self.frame_length() == other.frame_length()
+            && self.sample_rate() == other.sample_rate()
+            && self.device_index() == other.device_index()
+            && self.selected_device() == other.selected_device()
+            && self.version() == other.version()
+    }
+
+    /// Returns `true` if the current thread is the one that last called
+    /// [`start`](Self::start), and `false` if the recorder was never started.
+    #[must_use]
+    pub fn started_on_current_thread(&self) -> bool {
+        self.inner.started_on_current_thread()
+    }
+
+    /// Returns the number of audio channels per frame.
+    ///
+    /// The pvrecorder library always captures mono audio, so this is 1. Frames hold
+    /// `frame_length() * channels()` interleaved samples, so size buffers from both.
+    #[must_use]
+    pub fn channels(&self) -> usize {
+        CHANNELS
+    }
+
+    /// Returns the size of one sample in bytes.
+    ///
+    /// Samples are 16-bit signed integers, so this is 2.
+    #[must_use]
+    pub fn bytes_per_sample(&self) -> usize {
+        std::mem::size_of::<i16>()
+    }
+
+    /// Returns the size of one frame in bytes, i.e. `frame_length * bytes_per_sample`.
+    #[must_use]
+    pub fn frame_size_bytes(&self) -> usize {
+        self.frame_length() * self.bytes_per_sample()
+    }
+
+    /// Returns whether the recorder is currently recording.
+    ///
+    /// This is the library's own view of the device, which can turn `false` without a
+    /// call to [`stop`](Self::stop), e.g. when the device fails. Compare it with
+    /// [`is_started`](Self::is_started) to tell the two apart.
+    #[must_use]
+    pub fn is_recording(&self) -> bool {
+        self.inner.is_recording()
+    }
+
+    /// Returns whether [`start`](Self::start) succeeded and [`stop`](Self::stop) has not
+    /// been called since.
+    ///
+    /// This tracks the intended state on the Rust side, without asking the library. A
+    /// recorder that is started but not [recording](Self::is_recording) has lost its
+    /// device; one that is started and recording may still be
+    /// [stalled](Self::is_stalled).
+    #[must_use]
+    pub fn is_started(&self) -> bool {
+        self.inner.is_started.load(Ordering::Relaxed)
+    }
+
+    /// Returns the sample rate in Hz (typically 16000).
+    #[must_use]
+    pub fn sample_rate(&self) -> usize {
+        self.inner.sample_rate()
+    }
+
+    /// Returns `true` if the library records at the usual 16000 Hz.
+    ///
+    /// [`PvRecorderBuilder::init`] emits a one-time warning when it does not (see
+    /// [`set_warning_handler`](crate::set_warning_handler)).
+    #[must_use]
+    pub fn sample_rate_is_default(&self) -> bool {
+        self.sample_rate() == EXPECTED_SAMPLE_RATE
+    }
+
+    /// Returns the name of the selected audio device.
+    // FIX: Return &str instead of String to avoid allocation
+    #[must_use]
+    pub fn selected_device(&self) -> &str {
+        &self.inner.selected_device
+    }
+
+    /// Returns the version string of the pvrecorder library.
+    // FIX: Return &str instead of String to avoid allocation
+    #[must_use]
+    pub fn version(&self) -> &str {
+        &self.inner.version
+    }
+
+    /// Returns the version of this Rust crate, as opposed to [`version`](Self::version),
+    /// which is the version of the native pvrecorder library it loaded.
+    #[must_use]
+    pub fn crate_version() -> &'static str {
+        env!("CARGO_PKG_VERSION")
+    }
+
+    /// Returns both the crate and the native library versions, e.g. for bug reports.
+    #[must_use]
+    pub fn version_info(&self) -> VersionInfo {
+        VersionInfo {
+            crate_version: Self::crate_version().to_owned(),
+            library_version: self.version().to_owned(),
+        }
+    }
+
+    /// Returns the device index the recorder was initialized with (-1 for the system default).
+    #[must_use]
+    pub fn device_index(&self) -> i32 {
+        self.inner.device_index
+    }
+
+    /// Returns the index of the device actually opened.
+    ///
+    /// For the system default (-1), this is the index of the listed device whose name
+    /// matches [`selected_device`](Self::selected_device), looked up at init. It stays
+    /// -1 if the default is not one of the listed devices (e.g. a sound server's
+    /// virtual default) or enumeration failed.
+    #[must_use]
+    pub fn resolved_device_index(&self) -> i32 {
+        self.inner.resolved_device_index
+    }
+
+    /// Returns `(requested_index, resolved_index)`.
+    ///
+    /// `requested_index` is the index given to the builder before any fallback (-1 for
+    /// the default, or when [`PvRecorderBuilder::select_device`] picked the device), and
+    /// `resolved_index` is [`resolved_device_index`](Self::resolved_device_index). A
+    /// difference means the builder or the system substituted a device, e.g. so a UI
+    /// can show "Default → Built-in Microphone (index 1)".
+    #[must_use]
+    pub fn resolved_vs_requested(&self) -> (i32, i32) {
+        (
+            self.inner.requested_device_index,
+            self.inner.resolved_device_index,
+        )
+    }
+
+    /// Returns the platform string of the host, e.g. `linux/x86_64` or `raspberry-pi/cortex-a72`.
+    ///
+    /// This names the bundled library variant chosen for the host; on ARM it shows which
+    /// machine type was detected. When `arm_machine` or `platform_override` is used, it is
+    /// the platform selected there. It is recorded at init and does not change when a
+    /// custom `library_path` is used.
+    #[must_use]
+    pub fn platform(&self) -> &str {
+        &self.inner.platform
+    }
+
+    /// Returns the library's raw handle, for passing to other code linking pvrecorder.
+    ///
+    /// # Safety
+    /// The handle stays owned by this recorder: the caller must not delete it, and must
+    /// not use it after the last clone of this `PvRecorder` is dropped. It is also
+    /// replaced, and the old one deleted, when `auto_recover` reinitializes the device,
+    /// so it must not be kept across reads with recovery enabled. Calls made through it
+    /// bypass this crate's bookkeeping, such as sample counters.
+    #[must_use]
+    pub unsafe fn as_raw(&self) -> *mut CPvRecorder {
+        self.inner.handle().as_ptr()
+    }
+
+    /// Returns a best-effort name of the audio backend in use, e.g. `pulseaudio`.
+    ///
+    /// The library does not report its backend, so this is inferred from the platform
+    /// and, on Linux, from the sound servers running: `pipewire-pulse`, `pulseaudio`
+    /// or `alsa`. It is `coreaudio` on macOS and `wasapi` on Windows, and `None` when
+    /// nothing is detected. See the README's "Audio backends" section for the order
+    /// the library tries.
+    #[must_use]
+    pub fn backend_name(&self) -> Option<&str> {
+        detect_audio_backend()
+    }
+
+    /// Returns the path of the loaded pvrecorder library.
+    ///
+    /// With [`PvRecorderBuilder::library_paths`], this is the candidate that loaded.
+    #[must_use]
+    pub fn library_path(&self) -> &Path {
+        &self.inner.library_path
+    }
+
+    /// Returns a copy of the most recently read frame, or an empty vector before the first read.
+    ///
+    /// This lets a display refresh independently of the read loop without re-reading.
+    #[must_use]
+    pub fn last_frame(&self) -> Vec<i16> {
+        self.inner.last_frame()
+    }
+
+    /// Returns the gain applied to the last frame by `normalize_gain`.
+    ///
+    /// This is 1.0 before the first read and when normalization is disabled.
+    #[must_use]
+    pub fn last_gain(&self) -> f32 {
+        f32::from_bits(self.inner.last_gain.load(Ordering::Relaxed))
+    }
+
+    /// Returns the instant the first frame since initialization was read, or `None`
+    /// before the first read.
+    ///
+    /// It is taken the same way as the [`read_stamped`](Self::read_stamped) timestamps,
+    /// whichever read method produced the first frame.
+    #[must_use]
+    pub fn first_frame_instant(&self) -> Option<Instant> {
+        self.inner.first_frame_instant.get().copied()
+    }
+
+    /// Returns the total number of samples read since initialization or the last
+    /// [`reset_counters`](Self::reset_counters).
+    #[must_use]
+    pub fn samples_read(&self) -> u64 {
+        self.inner.samples_read()
+    }
+
+    /// Zeroes the running counters: [`samples_read`](Self::samples_read),
+    /// [`max_read_gap`](Self::max_read_gap) and
+    /// [`recording_duration`](Self::recording_duration).
+    ///
+    /// This only resets Rust-side bookkeeping, e.g. at utterance boundaries; the audio
+    /// stream is not touched and recording continues uninterrupted. A read that
+    /// completes concurrently is counted either before or after the reset, never lost
+    /// halfway.
+    pub fn reset_counters(&self) {
+        self.inner.samples_read.store(0, Ordering::Relaxed);
+        self.inner.max_read_gap_nanos.store(0, Ordering::Relaxed);
+        self.inner.samples_since_start.store(0, Ordering::Relaxed);
+        self.inner.clear_read_times();
+    }
+
+    /// Returns the duration of audio read since the last [`start`](Self::start).
+    ///
+    /// This is the number of samples read since then divided by the sample rate, kept
+    /// at nanosecond precision, so it is [`Duration::ZERO`] before the first read. It
+    /// measures captured audio, not wall-clock time, and is also reset by
+    /// [`reset_counters`](Self::reset_counters).
+    #[must_use]
+    pub fn recording_duration(&self) -> Duration {
+        let samples = self.inner.samples_since_start.load(Ordering::Relaxed);
+        samples_to_duration(
+            usize::try_from(samples).unwrap_or(usize::MAX),
+            self.sample_rate(),
+        )
+    }
+
+    /// Returns the `buffered_frames_count` the recorder was initialized with.
+    #[must_use]
+    pub fn buffered_frames_count(&self) -> i32 {
+        self.inner.buffered_frames_count
+    }
+
+    /// Returns the capacity of the library's buffer in samples, i.e.
+    /// `buffered_frames_count * frame_length`.
+    #[must_use]
+    pub fn buffered_samples(&self) -> usize {
+        usize::try_from(self.inner.buffered_frames_count).unwrap_or_default() * self.frame_length()
+    }
+
+    /// Estimates the input latency added by the library's frame buffer.
+    ///
+    /// Computed as `buffered_frames_count * frame_length / sample_rate`: the age of
+    /// the audio returned by a read once the buffer has filled up because the reader
+    /// fell behind. A reader that keeps up sees about one frame of delay instead. The
+    /// library has no latency query, so OS, driver and hardware buffering are not
+    /// included; treat this as an estimate.
+    #[must_use]
+    pub fn estimated_latency(&self) -> Duration {
+        let frames = usize::try_from(self.inner.buffered_frames_count).unwrap_or_default();
+        samples_to_duration(
+            frames.saturating_mul(self.frame_length()),
+            self.sample_rate(),
+        )
+    }
+
+    /// Returns the longest time the caller has spent between one read returning and
+    /// the next read starting, since [`start`](Self::start) or the last
+    /// [`reset_counters`](Self::reset_counters).
+    ///
+    /// While the reader is away, the library buffers incoming frames; a gap longer than
+    /// `buffered_frames_count` frames of audio overruns the buffer and drops audio.
+    #[must_use]
+    pub fn max_read_gap(&self) -> Duration {
+        Duration::from_nanos(self.inner.max_read_gap_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Returns how many samples of the last frame delivered were real audio.
+    ///
+    /// The library only ever returns whole frames, so after a read this is
+    /// [`frame_length`](Self::frame_length). After [`stop_draining`](Self::stop_draining)
+    /// it is the number of samples drained, which may be shorter or zero. It is 0 before
+    /// the first read.
+    #[must_use]
+    pub fn last_frame_valid_samples(&self) -> usize {
+        self.inner.last_frame_valid_samples.load(Ordering::Relaxed)
+    }
+
+    /// Returns `true` if the last `stall_frames` frames read were identical.
+    ///
+    /// Some drivers keep returning the same buffer once the device has stalled, while
+    /// [`is_recording`](Self::is_recording) still reports `true`. Note that a perfectly
+    /// silent input (all zeros) also repeats, so combine this with e.g. the device's
+    /// expected noise floor before acting on it. The count restarts on
+    /// [`start`](Self::start).
+    #[must_use]
+    pub fn is_stalled(&self) -> bool {
+        self.inner.repeated_frames.load(Ordering::Relaxed) + 1 >= self.inner.options.stall_frames
+    }
+
+    /// Returns the smallest and largest sample read since [`start`](Self::start).
+    ///
+    /// Samples are tracked after any `normalize_gain` processing, i.e. as returned by
+    /// reads. A range reaching `i16::MIN` or `i16::MAX` suggests clipping, while a
+    /// narrow one suggests a quiet input. Returns `(0, 0)` before the first read.
+    #[must_use]
+    pub fn signal_range(&self) -> (i16, i16) {
+        let min = self.inner.signal_min.load(Ordering::Relaxed);
+        let max = self.inner.signal_max.load(Ordering::Relaxed);
+        if min > max {
+            return (0, 0);
+        }
+        (min, max)
+    }
+
+    /// Returns the number of frames read per second, measured over the last 32 reads.
+    ///
+    /// When the reader keeps up, this is roughly `sample_rate / frame_length`; reads
+    /// served from the library's buffer can briefly push it higher, while a value well
+    /// below it means the consumer is falling behind. Returns 0.0 until two frames have
+    /// been read since [`start`](Self::start) or the last
+    /// [`reset_counters`](Self::reset_counters).
+    #[must_use]
+    pub fn read_rate(&self) -> f32 {
+        self.inner.read_rate()
+    }
+
+    /// Suggests a `buffered_frames_count` for reinitializing the recorder, based on
+    /// the observed [`max_read_gap`](Self::max_read_gap).
+    ///
+    /// The library cannot resize its buffer while running, so there is no way to grow
+    /// it in place; instead this returns enough frames to cover twice the longest
+    /// observed gap, and never less than the current count. Pass the value to
+    /// [`PvRecorderBuilder::buffered_frames_count`] when creating the next recorder.
+    #[must_use]
+    pub fn recommended_buffered_frames_count(&self) -> i32 {
+        self.inner.recommended_buffered_frames_count()
+    }
+
+    /// Returns a snapshot of the recorder's configuration and state.
+    #[must_use]
+    pub fn status(&self) -> RecorderStatus {
+        RecorderStatus {
+            frame_length: self.frame_length(),
+            sample_rate: self.sample_rate(),
+            selected_device: self.selected_device().to_owned(),
+            device_index: self.device_index(),
+            version: self.version().to_owned(),
+            is_recording: self.is_recording(),
+            samples_read: self.samples_read(),
+        }
+    }
+
+    /// Returns which optional features the loaded library supports.
+    #[must_use]
+    pub fn capabilities(&self) -> Capabilities {
+        self.inner.vtable.capabilities()
+    }
+
+    /// Returns the recorder's state as a plain `#[repr(C)]` struct, for re-exporting
+    /// across an FFI boundary in a single call.
+    ///
+    /// Unlike [`status`](Self::status), the result owns no heap data and can be copied
+    /// into foreign memory as-is.
+    #[must_use]
+    pub fn snapshot(&self) -> RecorderSnapshot {
+        let mut selected_device = [0; SNAPSHOT_DEVICE_NAME_CAPACITY];
+        let name = self.selected_device();
+        let mut len = name.len().min(SNAPSHOT_DEVICE_NAME_CAPACITY - 1);
+        while !name.is_char_boundary(len) {
+            len -= 1;
+        }
+        selected_device[..len].copy_from_slice(&name.as_bytes()[..len]);
+
+        RecorderSnapshot {
+            frame_length: u32::try_from(self.frame_length()).unwrap_or(u32::MAX),
+            sample_rate: u32::try_from(self.sample_rate()).unwrap_or(u32::MAX),
+            device_index: self.device_index(),
+            is_recording: u8::from(self.is_recording()),
+            samples_read: self.samples_read(),
+            last_gain: self.last_gain(),
+            selected_device,
+        }
+    }
+}
+
+/// Size in bytes of [`RecorderSnapshot::selected_device`], including the NUL terminator.
+pub const SNAPSHOT_DEVICE_NAME_CAPACITY: usize = 256;
+
+/// FFI-friendly snapshot of a [`PvRecorder`]'s state, as returned by
+/// [`PvRecorder::snapshot`].
+///
+/// All fields are fixed-size, so the struct can be marshalled directly, e.g. as a
+/// sequential struct in C#.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RecorderSnapshot {
+    /// Number of samples per frame.
+    pub frame_length: u32,
+    /// Sample rate in Hz.
+    pub sample_rate: u32,
+    /// Device index the recorder was initialized with (-1 for the system default).
+    pub device_index: i32,
+    /// 1 if the recorder is currently recording, 0 otherwise.
+    pub is_recording: u8,
+    /// Total number of samples read since initialization or the last counter reset.
+    pub samples_read: u64,
+    /// Gain applied to the last frame by gain normalization (1.0 when disabled).
+    pub last_gain: f32,
+    /// Name of the selected audio device as NUL-terminated UTF-8, truncated to fit.
+    pub selected_device: [u8; SNAPSHOT_DEVICE_NAME_CAPACITY],
+}
+
+impl RecorderSnapshot {
+    /// Returns the selected device name stored in the snapshot.
+    #[must_use]
+    pub fn selected_device(&self) -> &str {
+        let len = self
+            .selected_device
+            .iter()
+            .position(|&byte| byte == 0)
+            .unwrap_or(self.selected_device.len());
+        std::str::from_utf8(&self.selected_device[..len]).unwrap_or_default()
+    }
+}
+
+/// Snapshot of a [`PvRecorder`]'s configuration and state, as returned by
+/// [`PvRecorder::status`].
+///
+/// Implements `serde::Serialize` when the `serde` feature is enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct RecorderStatus {
+    /// Number of samples per frame.
+    pub frame_length: usize,
+    /// Sample rate in Hz.
+    pub sample_rate: usize,
+    /// Name of the selected audio device.
+    pub selected_device: String,
+    /// Device index the recorder was initialized with (-1 for the system default).
+    pub device_index: i32,
+    /// Version string of the pvrecorder library.
+    pub version: String,
+    /// Whether the recorder is currently recording.
+    pub is_recording: bool,
+    /// Total number of samples read since initialization or the last counter reset.
+    pub samples_read: u64,
+}
+
+/// How [`PvRecorder::read_into`] handles a buffer shorter than `frame_length`, as set by
+/// [`PvRecorderBuilder::buffer_check_policy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BufferCheckPolicy {
+    /// Panic, to fail fast on what is usually a programming error.
+    #[default]
+    Panic,
+    /// Return an `ArgumentError`, like [`PvRecorder::try_read_into`].
+    Error,
+}
+
+/// Optional library features, as returned by [`PvRecorder::capabilities`].
+///
+/// Each flag reports whether the loaded library exports the symbol backing that
+/// feature. Every symbol the current library exports is required, so all flags are
+/// `false` until an optional one is added.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+#[non_exhaustive]
+pub struct Capabilities {
+    /// Whether the library can report how many frames are currently buffered.
+    pub has_buffered_count: bool,
+    /// Whether the library can report the input latency of the device.
+    pub has_latency_query: bool,
+}
+
+/// Crate and native library versions, as returned by [`PvRecorder::version_info`].
+///
+/// Implements `serde::Serialize` when the `serde` feature is enabled.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize))]
+pub struct VersionInfo {
+    /// Version of the `pv_recorder` Rust crate.
+    pub crate_version: String,
+    /// Version of the loaded pvrecorder library.
+    pub library_version: String,
+}
+
+impl std::fmt::Display for VersionInfo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "pv_recorder {} (library {})",
+            self.crate_version, self.library_version
+        )
     }
+}
 
-    /// Returns the number of samples per frame.
+/// Progress of a [`PvRecorder::capture_with_progress`] call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CaptureProgress {
+    captured_samples: usize,
+    target_samples: usize,
+    sample_rate: usize,
+}
+
+impl CaptureProgress {
+    /// Returns the number of samples captured so far, never more than the target.
     #[must_use]
-    pub fn frame_length(&self) -> usize {
-        self.inner.frame_length() as usize
+    pub fn captured_samples(&self) -> usize {
+        self.captured_samples
     }
 
-    /// Returns whether the recorder is currently recording.
+    /// Returns the number of samples the capture will return.
     #[must_use]
-    pub fn is_recording(&self) -> bool {
-        self.inner.is_recording()
+    pub fn target_samples(&self) -> usize {
+        self.target_samples
     }
 
-    /// Returns the sample rate in Hz (typically 16000).
+    /// Returns the duration of audio captured so far.
     #[must_use]
-    pub fn sample_rate(&self) -> usize {
-        self.inner.sample_rate() as usize
+    pub fn elapsed(&self) -> Duration {
+        samples_to_duration(self.captured_samples, self.sample_rate)
     }
 
-    /// Returns the name of the selected audio device.
-    // FIX: Return &str instead of String to avoid allocation
+    /// Returns the duration of audio the capture will return.
+    ///
+    /// This is the requested duration rounded to a whole number of samples.
     #[must_use]
-    pub fn selected_device(&self) -> &str {
-        &self.inner.selected_device
+    pub fn target(&self) -> Duration {
+        samples_to_duration(self.target_samples, self.sample_rate)
     }
 
-    /// Returns the version string of the pvrecorder library.
-    // FIX: Return &str instead of String to avoid allocation
+    /// Returns the captured fraction of the target in `[0.0, 1.0]`.
+    ///
+    /// A zero-length target counts as complete.
     #[must_use]
-    pub fn version(&self) -> &str {
-        &self.inner.version
+    pub fn fraction(&self) -> f64 {
+        if self.target_samples == 0 {
+            return 1.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let fraction = self.captured_samples as f64 / self.target_samples as f64;
+        fraction
+    }
+}
+
+/// Returns the first of `library_paths` that loads, or an error listing each failure.
+fn first_loadable_library(library_paths: &[PathBuf]) -> Result<PathBuf, PvRecorderError> {
+    let mut failures = Vec::new();
+    for library_path in library_paths {
+        match load_library(library_path) {
+            Ok(_) => return Ok(library_path.clone()),
+            Err(err) => failures.push(err.message().to_owned()),
+        }
+    }
+    Err(PvRecorderError::new(
+        PvRecorderErrorStatus::LibraryLoadError,
+        format!(
+            "no pvrecorder library could be loaded: {}",
+            failures.join("; ")
+        ),
+    ))
+}
+
+/// Loads the pvrecorder library, reporting a missing file before attempting to open it.
+fn load_library(library_path: &Path) -> Result<Library, PvRecorderError> {
+    if !library_path.exists() {
+        return Err(PvRecorderError::new(
+            PvRecorderErrorStatus::LibraryLoadError,
+            format!("pvrecorder library not found at {}", library_path.display()),
+        ));
     }
+
+    unsafe { Library::new(library_path) }.map_err(|err| {
+        PvRecorderError::new(
+            PvRecorderErrorStatus::LibraryLoadError,
+            format!("Failed to load pvrecorder dynamic library: {err}"),
+        )
+    })
 }
 
 unsafe fn load_library_fn<T>(
@@ -394,10 +2302,7 @@ unsafe fn load_library_fn<T>(
             .map_err(|err| {
                 PvRecorderError::new(
                     PvRecorderErrorStatus::LibraryLoadError,
-                    format!(
-                        "Failed to load function symbol from pvrecorder library: {}",
-                        err
-                    ),
+                    format!("Failed to load function symbol from pvrecorder library: {err}"),
                 )
             })
     }
@@ -411,14 +2316,27 @@ fn check_fn_call_status(
         PvRecorderStatus::SUCCESS => Ok(()),
         _ => Err(PvRecorderError::new(
             PvRecorderErrorStatus::LibraryError(status),
-            format!(
-                "Function '{}' in the pvrecorder library failed",
-                function_name
-            ),
+            format!("Function '{function_name}' in the pvrecorder library failed"),
         )),
     }
 }
 
+/// Error for a timed read whose helper thread panicked before sending its frame.
+fn pending_read_lost() -> PvRecorderError {
+    PvRecorderError::new(
+        PvRecorderErrorStatus::OtherError,
+        "the helper thread of a timed read panicked before returning its frame",
+    )
+}
+
+/// Status codes for which `auto_recover` reinitializes the device and retries.
+fn is_recoverable_status(status: PvRecorderStatus) -> bool {
+    matches!(
+        status,
+        PvRecorderStatus::BACKEND_ERROR | PvRecorderStatus::IO_ERROR
+    )
+}
+
 struct PvRecorderInnerVTable {
     pv_recorder_init: RawSymbol<PvRecorderInitFn>,
     pv_recorder_delete: RawSymbol<PvRecorderDeleteFn>,
@@ -474,15 +2392,80 @@ impl PvRecorderInnerVTable {
             })
         }
     }
+
+    /// Reports the optional symbols that were loaded. None exist yet.
+    #[allow(clippy::unused_self)]
+    fn capabilities(&self) -> Capabilities {
+        Capabilities::default()
+    }
+}
+
+/// Read-side options set on the builder, carried over unchanged by
+/// [`PvRecorder::switch_device`].
+#[derive(Clone)]
+struct RecorderOptions {
+    auto_recover_retries: u32,
+    io_retry_count: u32,
+    io_retry_backoff: Duration,
+    silence_stderr: bool,
+    strict_single_thread: bool,
+    stall_frames: usize,
+    buffer_check_policy: BufferCheckPolicy,
+    on_frame: Option<FrameObserver>,
+    downmix_to_mono: bool,
+    normalize_gain: Option<f32>,
+}
+
+impl Default for RecorderOptions {
+    fn default() -> Self {
+        Self {
+            auto_recover_retries: 0,
+            io_retry_count: 0,
+            io_retry_backoff: Duration::ZERO,
+            silence_stderr: false,
+            strict_single_thread: false,
+            stall_frames: DEFAULT_STALL_FRAMES,
+            buffer_check_policy: BufferCheckPolicy::Panic,
+            on_frame: None,
+            downmix_to_mono: false,
+            normalize_gain: None,
+        }
+    }
 }
 
 struct PvRecorderInner {
     // FIX: Use NonNull for better safety semantics
-    cpvrecorder: NonNull<CPvRecorder>,
-    frame_length: i32,
-    sample_rate: i32,
+    cpvrecorder: RwLock<NonNull<CPvRecorder>>,
+    frame_length: usize,
+    device_index: i32,
+    requested_device_index: i32,
+    resolved_device_index: i32,
+    buffered_frames_count: i32,
+    options: RecorderOptions,
+    stderr_guard: Mutex<Option<StderrGuard>>,
+    start_thread: Mutex<Option<ThreadId>>,
+    is_started: AtomicBool,
+    repeated_frames: AtomicUsize,
+    last_gain: AtomicU32,
+    first_frame_instant: OnceLock<Instant>,
+    last_read_return: Mutex<Option<Instant>>,
+    read_times: Mutex<VecDeque<Instant>>,
+    max_read_gap_nanos: AtomicU64,
+    samples_since_start: AtomicU64,
+    signal_min: AtomicI16,
+    signal_max: AtomicI16,
+    sample_rate: usize,
     selected_device: String,
     version: String,
+    platform: String,
+    library_path: PathBuf,
+    application_name: Option<String>,
+    leftover_samples: Mutex<Vec<i16>>,
+    last_frame: Mutex<Vec<i16>>,
+    last_frame_valid_samples: AtomicUsize,
+    pending_read: Mutex<Option<PendingRead>>,
+    samples_read: AtomicU64,
+    is_debug_logging: AtomicBool,
     vtable: PvRecorderInnerVTable,
 }
 
@@ -492,36 +2475,21 @@ impl PvRecorderInner {
         device_index: i32,
         buffered_frames_count: i32,
         library_path: &Path,
+        application_name: Option<&str>,
     ) -> Result<Self, PvRecorderError> {
         // FIX: Removed duplicate validation - builder already validates
 
-        let lib = unsafe { Library::new(library_path) }.map_err(|err| {
-            PvRecorderError::new(
-                PvRecorderErrorStatus::LibraryLoadError,
-                format!("Failed to load pvrecorder dynamic library: {}", err),
-            )
-        })?;
+        let lib = load_library(library_path)?;
         let vtable = PvRecorderInnerVTable::new(lib)?;
+        let sample_rate = Self::checked_sample_rate(&vtable)?;
 
-        let mut cpvrecorder_ptr = std::ptr::null_mut();
-
-        unsafe {
-            let status = (vtable.pv_recorder_init)(
-                frame_length,
-                device_index,
-                buffered_frames_count,
-                addr_of_mut!(cpvrecorder_ptr),
-            );
-            check_fn_call_status(status, "pv_recorder_init")?;
-        }
-
-        // FIX: Added NULL check after init
-        let cpvrecorder = NonNull::new(cpvrecorder_ptr).ok_or_else(|| {
-            PvRecorderError::new(
-                PvRecorderErrorStatus::OtherError,
-                "pv_recorder_init returned SUCCESS but pointer is null",
-            )
-        })?;
+        let cpvrecorder = Self::create_handle(
+            &vtable,
+            frame_length,
+            device_index,
+            buffered_frames_count,
+            application_name,
+        )?;
 
         let selected_device = unsafe {
             let selected_device_c = (vtable.pv_recorder_get_selected_device)(cpvrecorder.as_ptr());
@@ -533,8 +2501,6 @@ impl PvRecorderInner {
             })?)
         };
 
-        let sample_rate = unsafe { (vtable.pv_recorder_sample_rate)() };
-
         let version = unsafe {
             let version_c = (vtable.pv_recorder_version)();
             String::from(CStr::from_ptr(version_c).to_str().map_err(|_| {
@@ -546,78 +2512,606 @@ impl PvRecorderInner {
         };
 
         Ok(Self {
-            cpvrecorder,
-            frame_length,
+            cpvrecorder: RwLock::new(cpvrecorder),
+            // The builder guarantees `frame_length > 0`.
+            frame_length: usize::try_from(frame_length).unwrap_or_default(),
+            device_index,
+            requested_device_index: device_index,
+            resolved_device_index: device_index,
+            buffered_frames_count,
+            options: RecorderOptions::default(),
+            stderr_guard: Mutex::new(None),
+            start_thread: Mutex::new(None),
+            is_started: AtomicBool::new(false),
+            repeated_frames: AtomicUsize::new(0),
+            last_gain: AtomicU32::new(1.0_f32.to_bits()),
+            first_frame_instant: OnceLock::new(),
+            last_read_return: Mutex::new(None),
+            read_times: Mutex::new(VecDeque::with_capacity(READ_RATE_WINDOW)),
+            max_read_gap_nanos: AtomicU64::new(0),
+            samples_since_start: AtomicU64::new(0),
+            signal_min: AtomicI16::new(i16::MAX),
+            signal_max: AtomicI16::new(i16::MIN),
             sample_rate,
             selected_device,
             version,
+            // Set by the builder, which knows whether a platform was resolved explicitly.
+            platform: String::new(),
+            library_path: library_path.to_path_buf(),
+            application_name: application_name.map(str::to_owned),
+            leftover_samples: Mutex::new(Vec::new()),
+            last_frame: Mutex::new(Vec::new()),
+            last_frame_valid_samples: AtomicUsize::new(0),
+            pending_read: Mutex::new(None),
+            samples_read: AtomicU64::new(0),
+            is_debug_logging: AtomicBool::new(false),
             vtable,
         })
     }
 
-    fn start(&self) -> Result<(), PvRecorderError> {
-        let status = unsafe { (self.vtable.pv_recorder_start)(self.cpvrecorder.as_ptr()) };
+    fn create_handle(
+        vtable: &PvRecorderInnerVTable,
+        frame_length: i32,
+        device_index: i32,
+        buffered_frames_count: i32,
+        application_name: Option<&str>,
+    ) -> Result<NonNull<CPvRecorder>, PvRecorderError> {
+        let mut cpvrecorder_ptr = std::ptr::null_mut();
+        let _application_name =
+            application_name.map(|name| EnvVarGuard::set(PULSE_APPLICATION_NAME_VAR, name));
+
+        unsafe {
+            let status = (vtable.pv_recorder_init)(
+                frame_length,
+                device_index,
+                buffered_frames_count,
+                addr_of_mut!(cpvrecorder_ptr),
+            );
+            check_fn_call_status(status, "pv_recorder_init")?;
+        }
+
+        // FIX: Added NULL check after init
+        NonNull::new(cpvrecorder_ptr).ok_or_else(|| {
+            PvRecorderError::new(
+                PvRecorderErrorStatus::OtherError,
+                "pv_recorder_init returned SUCCESS but pointer is null",
+            )
+        })
+    }
+
+    fn handle(&self) -> RwLockReadGuard<'_, NonNull<CPvRecorder>> {
+        self.cpvrecorder
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Replaces the C handle with a freshly initialized and started one.
+    ///
+    /// The new handle is created before the old one is deleted, so on failure the
+    /// old (stopped) handle stays in place and the error is returned.
+    fn recover(&self) -> Result<(), PvRecorderError> {
+        let mut cpvrecorder = self
+            .cpvrecorder
+            .write()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        unsafe { (self.vtable.pv_recorder_stop)(cpvrecorder.as_ptr()) };
+
+        let frame_length = i32::try_from(self.frame_length).unwrap_or(i32::MAX);
+        let new_cpvrecorder = Self::create_handle(
+            &self.vtable,
+            frame_length,
+            self.device_index,
+            self.buffered_frames_count,
+            self.application_name.as_deref(),
+        )?;
+
+        unsafe { (self.vtable.pv_recorder_delete)(cpvrecorder.as_ptr()) };
+        *cpvrecorder = new_cpvrecorder;
+
+        if self.is_debug_logging.load(Ordering::Relaxed) {
+            unsafe { (self.vtable.pv_recorder_set_debug_logging)(cpvrecorder.as_ptr(), 1) };
+        }
+
+        let status = unsafe { (self.vtable.pv_recorder_start)(cpvrecorder.as_ptr()) };
         check_fn_call_status(status, "pv_recorder_start")
     }
 
+    fn start(&self) -> Result<(), PvRecorderError> {
+        if self.options.silence_stderr {
+            let mut stderr_guard = self
+                .stderr_guard
+                .lock()
+                .unwrap_or_else(PoisonError::into_inner);
+            if stderr_guard.is_none() {
+                *stderr_guard = StderrGuard::acquire();
+            }
+        }
+        let status = unsafe { (self.vtable.pv_recorder_start)(self.handle().as_ptr()) };
+        if let Err(err) = check_fn_call_status(status, "pv_recorder_start") {
+            self.release_stderr();
+            return Err(err);
+        }
+        *self
+            .start_thread
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(thread::current().id());
+        self.is_started.store(true, Ordering::Relaxed);
+        *self
+            .last_read_return
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = None;
+        self.clear_read_times();
+        self.max_read_gap_nanos.store(0, Ordering::Relaxed);
+        self.samples_since_start.store(0, Ordering::Relaxed);
+        self.signal_min.store(i16::MAX, Ordering::Relaxed);
+        self.signal_max.store(i16::MIN, Ordering::Relaxed);
+        self.repeated_frames.store(0, Ordering::Relaxed);
+        Ok(())
+    }
+
     fn stop(&self) -> Result<(), PvRecorderError> {
-        let status = unsafe { (self.vtable.pv_recorder_stop)(self.cpvrecorder.as_ptr()) };
+        let status = unsafe { (self.vtable.pv_recorder_stop)(self.handle().as_ptr()) };
+        self.release_stderr();
+        self.is_started.store(false, Ordering::Relaxed);
         check_fn_call_status(status, "pv_recorder_stop")
     }
 
+    /// Restores stderr if this recorder silenced it while recording.
+    fn release_stderr(&self) {
+        self.stderr_guard
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take();
+    }
+
     fn read(&self) -> Result<Vec<i16>, PvRecorderError> {
-        let mut frame = vec![0; self.frame_length() as usize];
-        self.read_into(&mut frame)?;
+        self.read_with_alloc(|n| vec![0; n])
+    }
+
+    fn read_with_alloc<F: FnOnce(usize) -> Vec<i16>>(
+        &self,
+        alloc: F,
+    ) -> Result<Vec<i16>, PvRecorderError> {
+        let len = self.frame_length() * CHANNELS;
+        let mut frame = alloc(len);
+        if frame.len() < len {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!(
+                    "allocator returned a buffer of {} samples, expected {len}",
+                    frame.len()
+                ),
+            ));
+        }
+        frame.truncate(len);
+        self.read_frame(&mut frame)?;
+        if self.options.downmix_to_mono && CHANNELS > 1 {
+            return Ok(analysis::downmix_to_mono(&frame, CHANNELS));
+        }
         Ok(frame)
     }
 
+    /// Reads whole frames until `n` samples are covered and truncates the result to `n`.
+    fn read_samples(
+        &self,
+        n: usize,
+        on_frame: &mut dyn FnMut(usize),
+    ) -> Result<Vec<i16>, PvRecorderError> {
+        let frame_length = self.frame_length();
+        let frames = (n + frame_length - 1) / frame_length;
+        let mut samples = vec![0; frames * frame_length];
+        for (index, frame) in samples.chunks_exact_mut(frame_length).enumerate() {
+            self.read_frame(frame)?;
+            on_frame(((index + 1) * frame_length).min(n));
+        }
+        samples.truncate(n);
+        Ok(samples)
+    }
+
+    fn read_timed(&self) -> Result<(Vec<i16>, Duration), PvRecorderError> {
+        let mut frame = vec![0; self.frame_length()];
+        let start = Instant::now();
+        self.read_frame(&mut frame)?;
+        Ok((frame, start.elapsed()))
+    }
+
+    fn read_exact_samples(&self, n: usize) -> Result<Vec<i16>, PvRecorderError> {
+        let mut leftover = self
+            .leftover_samples
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+
+        let mut samples = Vec::with_capacity(n);
+        let carried = n.min(leftover.len());
+        samples.extend(leftover.drain(..carried));
+
+        let frame_length = self.frame_length();
+        let mut frame = Vec::new();
+        while samples.len() < n {
+            let needed = n - samples.len();
+            let result = if needed >= frame_length {
+                let start = samples.len();
+                samples.resize(start + frame_length, 0);
+                self.read_frame(&mut samples[start..]).map_err(|err| {
+                    samples.truncate(start);
+                    err
+                })
+            } else {
+                frame.resize(frame_length, 0);
+                self.read_frame(&mut frame).map(|()| {
+                    samples.extend_from_slice(&frame[..needed]);
+                    leftover.extend_from_slice(&frame[needed..]);
+                })
+            };
+
+            if let Err(err) = result {
+                samples.append(&mut leftover);
+                *leftover = samples;
+                return Err(err);
+            }
+        }
+
+        Ok(samples)
+    }
+
     fn read_into(&self, buffer: &mut [i16]) -> Result<(), PvRecorderError> {
+        if self.options.buffer_check_policy == BufferCheckPolicy::Error {
+            return self.try_read_into(buffer);
+        }
         assert!(
-            buffer.len() >= self.frame_length() as usize,
+            buffer.len() >= self.frame_length(),
             "buffer length {} is less than frame_length {}",
             buffer.len(),
             self.frame_length()
         );
-        let status =
-            unsafe { (self.vtable.pv_recorder_read)(self.cpvrecorder.as_ptr(), buffer.as_mut_ptr()) };
-        check_fn_call_status(status, "pv_recorder_read")
+        self.read_frame(buffer)
+    }
+
+    fn try_read_into(&self, buffer: &mut [i16]) -> Result<(), PvRecorderError> {
+        if buffer.len() < self.frame_length() {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!(
+                    "buffer length {} is less than frame_length {}",
+                    buffer.len(),
+                    self.frame_length()
+                ),
+            ));
+        }
+        self.read_frame(buffer)
+    }
+
+    /// Reads one frame into `buffer`, which must hold at least `frame_length` samples.
+    fn read_frame(&self, buffer: &mut [i16]) -> Result<(), PvRecorderError> {
+        self.read_frame_stamped(buffer).map(|_| ())
+    }
+
+    /// Reads one frame into `buffer` and returns the instant the library's read returned.
+    fn read_frame_stamped(&self, buffer: &mut [i16]) -> Result<Instant, PvRecorderError> {
+        assert!(buffer.len() >= self.frame_length);
+        // SAFETY: `buffer` is valid for `frame_length` writes, checked above.
+        unsafe { self.read_frame_ptr(buffer.as_mut_ptr()) }
+    }
+
+    fn read_into_uninit<'a>(
+        &self,
+        buffer: &'a mut [MaybeUninit<i16>],
+    ) -> Result<&'a mut [i16], PvRecorderError> {
+        if buffer.len() < self.frame_length {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!(
+                    "buffer length {} is less than frame_length {}",
+                    buffer.len(),
+                    self.frame_length
+                ),
+            ));
+        }
+
+        let ptr = buffer.as_mut_ptr().cast::<i16>();
+        // SAFETY: `buffer` is valid for `frame_length` writes, and `MaybeUninit<i16>` has
+        // the same layout as `i16`. On success the library has written all
+        // `frame_length` samples, so the returned slice is initialized.
+        unsafe {
+            self.read_frame_ptr(ptr)?;
+            Ok(slice::from_raw_parts_mut(ptr, self.frame_length))
+        }
+    }
+
+    /// Reads one frame into `buffer` and post-processes it.
+    ///
+    /// # Safety
+    /// `buffer` must be valid for writes of `frame_length` samples. Its contents are
+    /// only read after the library reports success, which means it wrote the whole
+    /// frame, so it may point to uninitialized memory.
+    unsafe fn read_frame_ptr(&self, buffer: *mut i16) -> Result<Instant, PvRecorderError> {
+        if let Some(pending) = self.take_pending_read() {
+            // A timed read gave up on this frame; take it over rather than reading the
+            // handle while the helper thread still is.
+            let (frame, read_at) = pending.recv().map_err(|_| pending_read_lost())??;
+            // SAFETY: the caller guarantees `buffer` is valid for `frame_length` writes,
+            // and the helper read exactly that many samples.
+            unsafe { ptr::copy_nonoverlapping(frame.as_ptr(), buffer, self.frame_length) };
+            return Ok(read_at);
+        }
+        // SAFETY: forwarded from the caller.
+        unsafe { self.read_frame_direct(buffer) }
+    }
+
+    /// Reads one frame from the library into `buffer`, ignoring any pending timed read.
+    ///
+    /// # Safety
+    /// As for [`read_frame_ptr`](Self::read_frame_ptr).
+    unsafe fn read_frame_direct(&self, buffer: *mut i16) -> Result<Instant, PvRecorderError> {
+        if self.options.strict_single_thread && !self.started_on_current_thread() {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::OtherError,
+                "strict_single_thread: read called from a thread other than the one that called start",
+            ));
+        }
+        self.track_read_gap();
+        let mut status = self.read_raw(buffer);
+        let mut read_at = Instant::now();
+        let mut io_retries = 0;
+        let mut retries = 0;
+        loop {
+            if status == PvRecorderStatus::IO_ERROR && io_retries < self.options.io_retry_count {
+                io_retries += 1;
+                thread::sleep(self.options.io_retry_backoff);
+            } else if is_recoverable_status(status) && retries < self.options.auto_recover_retries {
+                retries += 1;
+                self.recover()?;
+            } else {
+                break;
+            }
+            status = self.read_raw(buffer);
+            read_at = Instant::now();
+        }
+        check_fn_call_status(status, "pv_recorder_read")?;
+        self.first_frame_instant.get_or_init(|| read_at);
+        self.track_read_time(read_at);
+
+        // SAFETY: the caller guarantees `buffer` holds `frame_length` samples, all of
+        // which the successful read initialized.
+        let frame = unsafe { slice::from_raw_parts_mut(buffer, self.frame_length) };
+        if let Some(target_peak) = self.options.normalize_gain {
+            let peak = analysis::peak(frame);
+            let gain = if peak == 0 {
+                MAX_NORMALIZE_GAIN
+            } else {
+                (target_peak * f32::from(i16::MAX) / f32::from(peak)).min(MAX_NORMALIZE_GAIN)
+            };
+            analysis::apply_gain(frame, gain);
+            self.last_gain.store(gain.to_bits(), Ordering::Relaxed);
+        }
+
+        if let (Some(&min), Some(&max)) = (frame.iter().min(), frame.iter().max()) {
+            self.signal_min.fetch_min(min, Ordering::Relaxed);
+            self.signal_max.fetch_max(max, Ordering::Relaxed);
+        }
+
+        let mut last_frame = self
+            .last_frame
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if last_frame.as_slice() == &*frame {
+            self.repeated_frames.fetch_add(1, Ordering::Relaxed);
+        } else {
+            self.repeated_frames.store(0, Ordering::Relaxed);
+        }
+        last_frame.clear();
+        last_frame.extend_from_slice(frame);
+        drop(last_frame);
+        self.last_frame_valid_samples
+            .store(self.frame_length, Ordering::Relaxed);
+
+        self.samples_read
+            .fetch_add(self.frame_length as u64, Ordering::Relaxed);
+        self.samples_since_start
+            .fetch_add(self.frame_length as u64, Ordering::Relaxed);
+        *self
+            .last_read_return
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner) = Some(Instant::now());
+        if let Some(on_frame) = &self.options.on_frame {
+            on_frame(frame);
+        }
+        Ok(read_at)
+    }
+
+    /// Takes the helper read left pending by a timed-out `read_with_timeout`, if any.
+    fn take_pending_read(&self) -> Option<PendingRead> {
+        self.pending_read
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .take()
+    }
+
+    /// Records how long the caller spent away from the reader since the last read returned.
+    fn track_read_gap(&self) {
+        let last_read_return = *self
+            .last_read_return
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if let Some(last_read_return) = last_read_return {
+            let gap = u64::try_from(last_read_return.elapsed().as_nanos()).unwrap_or(u64::MAX);
+            self.max_read_gap_nanos.fetch_max(gap, Ordering::Relaxed);
+        }
+    }
+
+    fn started_on_current_thread(&self) -> bool {
+        *self
+            .start_thread
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            == Some(thread::current().id())
+    }
+
+    /// Adds a read to the rolling window used by `read_rate`.
+    fn track_read_time(&self, read_at: Instant) {
+        let mut read_times = self
+            .read_times
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if read_times.len() == READ_RATE_WINDOW {
+            read_times.pop_front();
+        }
+        read_times.push_back(read_at);
+    }
+
+    fn clear_read_times(&self) {
+        self.read_times
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+
+    fn read_rate(&self) -> f32 {
+        let read_times = self
+            .read_times
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        let (Some(first), Some(last)) = (read_times.front(), read_times.back()) else {
+            return 0.0;
+        };
+        let elapsed = last.duration_since(*first).as_secs_f32();
+        if elapsed == 0.0 {
+            return 0.0;
+        }
+        #[allow(clippy::cast_precision_loss)]
+        let intervals = (read_times.len() - 1) as f32;
+        intervals / elapsed
+    }
+
+    fn recommended_buffered_frames_count(&self) -> i32 {
+        let frame_nanos = self.frame_length as u128 * 1_000_000_000;
+        let gap_samples =
+            u128::from(self.max_read_gap_nanos.load(Ordering::Relaxed)) * self.sample_rate as u128;
+        let frames = (gap_samples + frame_nanos - 1) / frame_nanos.max(1);
+        let recommended = i32::try_from(frames.saturating_mul(2)).unwrap_or(i32::MAX);
+        recommended.max(self.buffered_frames_count)
+    }
+
+    fn read_raw(&self, buffer: *mut i16) -> PvRecorderStatus {
+        unsafe { (self.vtable.pv_recorder_read)(self.handle().as_ptr(), buffer) }
     }
 
     fn set_debug_logging(&self, is_debug_logging_enabled: bool) {
         // FIX: Convert bool to c_int for FFI safety
         unsafe {
             (self.vtable.pv_recorder_set_debug_logging)(
-                self.cpvrecorder.as_ptr(),
-                is_debug_logging_enabled as c_int,
-            )
-        };
+                self.handle().as_ptr(),
+                c_int::from(is_debug_logging_enabled),
+            );
+        }
+        self.is_debug_logging
+            .store(is_debug_logging_enabled, Ordering::Relaxed);
     }
 
-    fn frame_length(&self) -> i32 {
+    fn frame_length(&self) -> usize {
         self.frame_length
     }
 
     fn is_recording(&self) -> bool {
         // FIX: Convert c_int to bool
-        unsafe { (self.vtable.pv_recorder_get_is_recording)(self.cpvrecorder.as_ptr()) != 0 }
+        unsafe { (self.vtable.pv_recorder_get_is_recording)(self.handle().as_ptr()) != 0 }
     }
 
-    fn sample_rate(&self) -> i32 {
+    fn sample_rate(&self) -> usize {
         self.sample_rate
     }
 
+    fn last_frame(&self) -> Vec<i16> {
+        self.last_frame
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn samples_read(&self) -> u64 {
+        self.samples_read.load(Ordering::Relaxed)
+    }
+
+    /// Returns the library's sample rate without initializing a device.
+    fn library_sample_rate(library_path: &Path) -> Result<usize, PvRecorderError> {
+        let lib = load_library(library_path)?;
+        let vtable = PvRecorderInnerVTable::new(lib)?;
+        Self::checked_sample_rate(&vtable)
+    }
+
+    /// Reads the library's sample rate, rejecting a value that is not positive.
+    fn checked_sample_rate(vtable: &PvRecorderInnerVTable) -> Result<usize, PvRecorderError> {
+        let sample_rate = unsafe { (vtable.pv_recorder_sample_rate)() };
+        usize::try_from(sample_rate)
+            .ok()
+            .filter(|&sample_rate| sample_rate > 0)
+            .ok_or_else(|| {
+                PvRecorderError::new(
+                    PvRecorderErrorStatus::OtherError,
+                    format!(
+                        "pv_recorder_sample_rate returned an invalid sample rate: {sample_rate}"
+                    ),
+                )
+            })
+    }
+
     pub fn get_available_devices<P: AsRef<Path>>(
         library_path: P,
     ) -> Result<Vec<String>, PvRecorderError> {
-        let lib = unsafe { Library::new(library_path.as_ref()) }.map_err(|err| {
-            PvRecorderError::new(
-                PvRecorderErrorStatus::LibraryLoadError,
-                format!("Failed to load pvrecorder dynamic library: {}", err),
-            )
-        })?;
+        let lib = load_library(library_path.as_ref())?;
+        let vtable = PvRecorderInnerVTable::new(lib)?;
+        Self::list_devices(&vtable)
+    }
 
+    /// Lists the devices with their indices, marking the default one if `mark_default`.
+    fn get_audio_devices(
+        library_path: &Path,
+        mark_default: bool,
+    ) -> Result<Vec<AudioDevice>, PvRecorderError> {
+        let lib = load_library(library_path)?;
         let vtable = PvRecorderInnerVTable::new(lib)?;
+        let names = Self::list_devices(&vtable)?;
+        let default_position = mark_default
+            .then(|| Self::default_device_name(&vtable))
+            .flatten()
+            .and_then(|default| names.iter().position(|name| *name == default));
+
+        Ok(names
+            .into_iter()
+            .zip(0..)
+            .enumerate()
+            .map(|(position, (name, index))| AudioDevice {
+                index,
+                name,
+                is_default: default_position == Some(position),
+            })
+            .collect())
+    }
+
+    /// Opens and immediately deletes a handle on the default device to learn its name.
+    fn default_device_name(vtable: &PvRecorderInnerVTable) -> Option<String> {
+        let handle = Self::create_handle(
+            vtable,
+            DEFAULT_FRAME_LENGTH,
+            DEFAULT_DEVICE_INDEX,
+            DEFAULT_BUFFERED_FRAMES_COUNT,
+            None,
+        )
+        .ok()?;
+        // SAFETY: `handle` was just created by this library and is deleted exactly once;
+        // the name is copied out before that.
+        unsafe {
+            let name = CStr::from_ptr((vtable.pv_recorder_get_selected_device)(handle.as_ptr()))
+                .to_str()
+                .map(String::from)
+                .ok();
+            (vtable.pv_recorder_delete)(handle.as_ptr());
+            name
+        }
+    }
 
+    fn list_devices(vtable: &PvRecorderInnerVTable) -> Result<Vec<String>, PvRecorderError> {
         let mut device_list = Vec::new();
         let mut device_list_length = 0;
 
@@ -631,17 +3125,30 @@ impl PvRecorderInner {
             );
             check_fn_call_status(status, "pv_recorder_get_available_devices")?;
 
-            for i in 0..device_list_length as usize {
+            let Ok(length) = usize::try_from(device_list_length) else {
+                return Err(PvRecorderError::new(
+                    PvRecorderErrorStatus::OtherError,
+                    format!("pv_recorder_get_available_devices returned a negative device count: {device_list_length}"),
+                ));
+            };
+            if device_list_ptr_ptr.is_null() {
+                return Ok(Vec::new());
+            }
+
+            let converted: Result<(), PvRecorderError> = (0..length).try_for_each(|i| {
                 let device = CStr::from_ptr(*device_list_ptr_ptr.add(i));
-                device_list.push(String::from(device.to_str().map_err(|_| {
+                let name = device.to_str().map_err(|_| {
                     PvRecorderError::new(
                         PvRecorderErrorStatus::OtherError,
                         "Failed to convert device strings",
                     )
-                })?));
-            }
+                })?;
+                device_list.push(String::from(name));
+                Ok(())
+            });
 
             (vtable.pv_recorder_free_available_devices)(device_list_length, device_list_ptr_ptr);
+            converted?;
         }
         Ok(device_list)
     }
@@ -650,15 +3157,20 @@ impl PvRecorderInner {
 // SAFETY: The underlying C library (pvrecorder) is thread-safe for all operations
 // on a single recorder instance. The raw pointer `cpvrecorder` is encapsulated
 // and only accessed through the vtable function pointers. The NonNull wrapper
-// ensures the pointer is always valid, and the Arc wrapper in PvRecorder ensures
-// proper shared ownership semantics.
+// ensures the pointer is always valid, the RwLock keeps it from being replaced during
+// auto-recovery while another call is using it, and the Arc wrapper in PvRecorder
+// ensures proper shared ownership semantics.
 unsafe impl Send for PvRecorderInner {}
 unsafe impl Sync for PvRecorderInner {}
 
 impl Drop for PvRecorderInner {
     fn drop(&mut self) {
+        let cpvrecorder = self
+            .cpvrecorder
+            .get_mut()
+            .unwrap_or_else(PoisonError::into_inner);
         unsafe {
-            (self.vtable.pv_recorder_delete)(self.cpvrecorder.as_ptr());
+            (self.vtable.pv_recorder_delete)(cpvrecorder.as_ptr());
         }
     }
 }
@@ -0,0 +1,148 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use crate::pvrecorder::{PvRecorder, PvRecorderError, PvRecorderErrorStatus};
+
+/// Linear-interpolation resampler that carries its state across frames.
+///
+/// Unlike [`analysis::resample_linear`](crate::analysis::resample_linear), which treats
+/// each call in isolation, consecutive [`process`](Self::process) calls produce the same
+/// output as resampling the whole stream at once, so there are no discontinuities at
+/// frame boundaries. The last input sample of each frame is held back until the next
+/// one arrives, which delays the output by one input sample. As with the stateless
+/// version, no anti-aliasing filter is applied when downsampling.
+///
+/// # Example
+/// ```no_run
+/// use pv_recorder::{PvRecorderBuilder, Resampler};
+///
+/// let recorder = PvRecorderBuilder::default().init()?;
+/// recorder.start()?;
+///
+/// let mut resampler = Resampler::new(recorder.sample_rate(), 8000)?;
+/// loop {
+///     let frame = recorder.read_resampled_streaming(&mut resampler)?;
+///     // process 8 kHz audio
+/// }
+/// # Ok::<(), pv_recorder::PvRecorderError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct Resampler {
+    from_rate: u64,
+    to_rate: u64,
+    /// Last input sample of the previous frame, interpolated against the next frame.
+    last: Option<i16>,
+    /// Position of the next output sample in units of `1 / to_rate` input samples,
+    /// relative to `last` (or to the start of the next frame if there is none).
+    phase: u64,
+}
+
+impl Resampler {
+    /// Creates a resampler converting from `from_rate` to `to_rate` Hz.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if either rate is 0.
+    pub fn new(from_rate: usize, to_rate: usize) -> Result<Self, PvRecorderError> {
+        if from_rate == 0 || to_rate == 0 {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!("sample rates must be greater than 0, got: from {from_rate}, to {to_rate}"),
+            ));
+        }
+
+        Ok(Self {
+            from_rate: from_rate as u64,
+            to_rate: to_rate as u64,
+            last: None,
+            phase: 0,
+        })
+    }
+
+    /// Returns the input sample rate in Hz.
+    #[must_use]
+    pub fn from_rate(&self) -> usize {
+        usize::try_from(self.from_rate).unwrap_or(usize::MAX)
+    }
+
+    /// Returns the output sample rate in Hz.
+    #[must_use]
+    pub fn to_rate(&self) -> usize {
+        usize::try_from(self.to_rate).unwrap_or(usize::MAX)
+    }
+
+    /// Resamples the next frame of the stream.
+    ///
+    /// The output length varies by at most one sample between frames, averaging
+    /// `frame.len() * to_rate / from_rate`.
+    pub fn process(&mut self, frame: &[i16]) -> Vec<i16> {
+        if frame.is_empty() {
+            return Vec::new();
+        }
+        if self.from_rate == self.to_rate {
+            return frame.to_vec();
+        }
+
+        let input: Vec<i16> = self.last.into_iter().chain(frame.iter().copied()).collect();
+        let last_index = input.len() as u64 - 1;
+        let mut output = Vec::new();
+        while self.phase / self.to_rate < last_index {
+            let index = usize::try_from(self.phase / self.to_rate).unwrap_or(usize::MAX);
+            #[allow(clippy::cast_precision_loss)]
+            let fraction = (self.phase % self.to_rate) as f64 / self.to_rate as f64;
+            let current = f64::from(input[index]);
+            let next = f64::from(input[index + 1]);
+            #[allow(clippy::cast_possible_truncation)]
+            let sample = (current + (next - current) * fraction).round() as i16;
+            output.push(sample);
+            self.phase += self.from_rate;
+        }
+
+        self.phase -= last_index * self.to_rate;
+        self.last = input.last().copied();
+        output
+    }
+
+    /// Forgets the carried-over state, e.g. before resampling an unrelated stream.
+    pub fn reset(&mut self) {
+        self.last = None;
+        self.phase = 0;
+    }
+}
+
+impl PvRecorder {
+    /// Reads one frame and resamples it with `resampler`, keeping its state for the
+    /// next call.
+    ///
+    /// Unlike [`read_dual`](Self::read_dual), consecutive frames join up without
+    /// discontinuities. See [`Resampler`] for details.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if the resampler's input rate differs from
+    /// [`sample_rate`](Self::sample_rate), or an error if the recorder is not started or
+    /// a read error occurs.
+    pub fn read_resampled_streaming(
+        &self,
+        resampler: &mut Resampler,
+    ) -> Result<Vec<i16>, PvRecorderError> {
+        if resampler.from_rate() != self.sample_rate() {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!(
+                    "resampler input rate {} Hz does not match the recorder's {} Hz",
+                    resampler.from_rate(),
+                    self.sample_rate()
+                ),
+            ));
+        }
+
+        Ok(resampler.process(&self.read()?))
+    }
+}
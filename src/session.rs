@@ -0,0 +1,95 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use std::time::Duration;
+
+use crate::pvrecorder::{PvRecorder, PvRecorderError};
+use crate::util::duration_to_samples;
+
+/// Accumulates consecutive frames from a [`PvRecorder`] into a single buffer.
+///
+/// # Example
+/// ```no_run
+/// use std::time::Duration;
+/// use pv_recorder::{PvRecorderBuilder, RecordingSession};
+///
+/// let recorder = PvRecorderBuilder::default().init()?;
+/// recorder.start()?;
+///
+/// let mut session = RecordingSession::new(recorder.clone(), Some(Duration::from_secs(3)));
+/// while session.len() < 3 * recorder.sample_rate() {
+///     session.capture_frame()?;
+/// }
+/// let audio = session.finish();
+/// # Ok::<(), pv_recorder::PvRecorderError>(())
+/// ```
+pub struct RecordingSession {
+    recorder: PvRecorder,
+    samples: Vec<i16>,
+}
+
+impl RecordingSession {
+    /// Creates a session reading from `recorder`.
+    ///
+    /// If `expected_duration` is given, enough capacity for that much audio (rounded
+    /// up to a whole number of frames) is reserved upfront.
+    #[must_use]
+    pub fn new(recorder: PvRecorder, expected_duration: Option<Duration>) -> Self {
+        let capacity = expected_duration.map_or(0, |duration| {
+            let frame_length = recorder.frame_length();
+            let samples = duration_to_samples(duration, recorder.sample_rate());
+            (samples + frame_length - 1) / frame_length * frame_length
+        });
+
+        Self {
+            recorder,
+            samples: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Reads one frame from the recorder and appends it to the session.
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started or a read error occurs.
+    pub fn capture_frame(&mut self) -> Result<(), PvRecorderError> {
+        let start = self.samples.len();
+        self.samples.resize(start + self.recorder.frame_length(), 0);
+        if let Err(err) = self.recorder.read_into(&mut self.samples[start..]) {
+            self.samples.truncate(start);
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Returns the samples captured so far.
+    #[must_use]
+    pub fn samples(&self) -> &[i16] {
+        &self.samples
+    }
+
+    /// Returns the number of samples captured so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.samples.len()
+    }
+
+    /// Returns `true` if no frame has been captured yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+
+    /// Ends the session and returns the accumulated audio.
+    #[must_use]
+    pub fn finish(self) -> Vec<i16> {
+        self.samples
+    }
+}
@@ -0,0 +1,100 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+//! Synthetic frame sources for tests, enabled with the `testing` feature.
+
+use std::f64::consts::TAU;
+
+use crate::backend::RecorderBackend;
+use crate::pvrecorder::PvRecorderError;
+
+/// Generates a continuous sine wave, frame by frame.
+///
+/// The phase carries over between reads, so consecutive frames join without
+/// discontinuities. Output is deterministic: two sources with the same parameters
+/// yield identical frames.
+///
+/// # Example
+/// ```
+/// use pv_recorder::testing::SineSource;
+/// use pv_recorder::RecorderBackend;
+///
+/// let mut source = SineSource::new(440.0, 0.5);
+/// let frame = source.read()?;
+/// assert_eq!(frame.len(), 512);
+/// # Ok::<(), pv_recorder::PvRecorderError>(())
+/// ```
+#[derive(Clone, Debug)]
+pub struct SineSource {
+    /// Tone frequency in Hz.
+    pub freq: f64,
+    /// Peak amplitude as a fraction of full scale, clamped to `[0.0, 1.0]`.
+    pub amplitude: f64,
+    sample_rate: usize,
+    frame_length: usize,
+    position: u64,
+}
+
+impl SineSource {
+    /// Creates a source at 16000 Hz with 512-sample frames, matching the recorder defaults.
+    #[must_use]
+    pub fn new(freq: f64, amplitude: f64) -> Self {
+        Self {
+            freq,
+            amplitude,
+            sample_rate: 16000,
+            frame_length: 512,
+            position: 0,
+        }
+    }
+
+    /// Sets the sample rate the tone is generated at.
+    #[must_use]
+    pub fn with_sample_rate(mut self, sample_rate: usize) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    /// Sets the number of samples returned by each read.
+    #[must_use]
+    pub fn with_frame_length(mut self, frame_length: usize) -> Self {
+        self.frame_length = frame_length;
+        self
+    }
+}
+
+impl RecorderBackend for SineSource {
+    fn read(&mut self) -> Result<Vec<i16>, PvRecorderError> {
+        let amplitude = self.amplitude.clamp(0.0, 1.0) * f64::from(i16::MAX);
+        #[allow(clippy::cast_precision_loss)]
+        let sample_rate = self.sample_rate.max(1) as f64;
+
+        let frame = (0..self.frame_length)
+            .map(|offset| {
+                #[allow(clippy::cast_precision_loss)]
+                let t = (self.position + offset as u64) as f64 / sample_rate;
+                #[allow(clippy::cast_possible_truncation)]
+                let sample = (amplitude * (TAU * self.freq * t).sin()).round() as i16;
+                sample
+            })
+            .collect();
+        self.position += self.frame_length as u64;
+        Ok(frame)
+    }
+
+    fn frame_length(&self) -> usize {
+        self.frame_length
+    }
+
+    fn sample_rate(&self) -> usize {
+        self.sample_rate
+    }
+}
@@ -9,9 +9,64 @@
     specific language governing permissions and limitations under the License.
 */
 
-use std::path::PathBuf;
+use std::ffi::OsString;
+use std::path::{Path, PathBuf};
+use std::sync::{Mutex, OnceLock, PoisonError};
+use std::time::Duration;
+
+use libc::c_int;
+
+use crate::pvrecorder::{PvRecorderError, PvRecorderErrorStatus};
 
 const DEFAULT_RELATIVE_LIBRARY_DIR: &str = "lib/";
+const RPI_MACHINES: [&str; 4] = ["arm11", "cortex-a53", "cortex-a72", "cortex-a76"];
+const BUNDLED_PLATFORMS: [(&str, &str); 12] = [
+    ("linux/x86_64", "libpv_recorder.so"),
+    ("mac/x86_64", "libpv_recorder.dylib"),
+    ("mac/arm64", "libpv_recorder.dylib"),
+    ("windows/amd64", "libpv_recorder.dll"),
+    ("windows/arm64", "libpv_recorder.dll"),
+    ("raspberry-pi/arm11", "libpv_recorder.so"),
+    ("raspberry-pi/cortex-a53", "libpv_recorder.so"),
+    ("raspberry-pi/cortex-a53-aarch64", "libpv_recorder.so"),
+    ("raspberry-pi/cortex-a72", "libpv_recorder.so"),
+    ("raspberry-pi/cortex-a72-aarch64", "libpv_recorder.so"),
+    ("raspberry-pi/cortex-a76", "libpv_recorder.so"),
+    ("raspberry-pi/cortex-a76-aarch64", "libpv_recorder.so"),
+];
+
+/// Callback receiving warnings emitted by this crate.
+pub type WarningHandler = Box<dyn Fn(&str) + Send + Sync>;
+
+static WARNING_HANDLER: OnceLock<WarningHandler> = OnceLock::new();
+
+/// Installs a handler for warnings emitted by this crate, such as the ARM machine
+/// detection fallbacks or a clamped device index.
+///
+/// Without a handler, warnings are printed to stderr, or logged with `log::warn!` when
+/// the `log` feature is enabled. The handler can only be set once, and should be
+/// installed before the first recorder is created.
+///
+/// # Errors
+/// Returns an `OtherError` if a handler has already been set.
+pub fn set_warning_handler(handler: WarningHandler) -> Result<(), PvRecorderError> {
+    WARNING_HANDLER.set(handler).map_err(|_| {
+        PvRecorderError::new(
+            PvRecorderErrorStatus::OtherError,
+            "warning handler has already been set",
+        )
+    })
+}
+
+pub(crate) fn warn(message: &str) {
+    match WARNING_HANDLER.get() {
+        Some(handler) => handler(message),
+        #[cfg(feature = "log")]
+        None => log::warn!("{message}"),
+        #[cfg(not(feature = "log"))]
+        None => eprintln!("WARNING: {message}"),
+    }
+}
 
 #[cfg(all(target_os = "linux", any(target_arch = "arm", target_arch = "aarch64")))]
 fn find_machine_type() -> String {
@@ -21,17 +76,16 @@ fn find_machine_type() -> String {
     let cpu_info = match Command::new("cat").arg("/proc/cpuinfo").output() {
         Ok(output) => output,
         Err(e) => {
-            eprintln!("WARNING: Failed to read /proc/cpuinfo: {}. Using fallback.", e);
+            warn(&format!(
+                "Failed to read /proc/cpuinfo: {e}. Using fallback."
+            ));
             return String::from("unsupported");
         }
     };
 
-    let cpu_info_str = match std::str::from_utf8(&cpu_info.stdout) {
-        Ok(s) => s,
-        Err(_) => {
-            eprintln!("WARNING: /proc/cpuinfo contains invalid UTF-8. Using fallback.");
-            return String::from("unsupported");
-        }
+    let Ok(cpu_info_str) = std::str::from_utf8(&cpu_info.stdout) else {
+        warn("/proc/cpuinfo contains invalid UTF-8. Using fallback.");
+        return String::from("unsupported");
     };
 
     let cpu_part_list: Vec<&str> = cpu_info_str
@@ -41,7 +95,7 @@ fn find_machine_type() -> String {
 
     // FIX: Use is_empty() instead of len() == 0
     if cpu_part_list.is_empty() {
-        eprintln!("WARNING: Could not find CPU part in /proc/cpuinfo. Using fallback.");
+        warn("Could not find CPU part in /proc/cpuinfo. Using fallback.");
         return String::from("unsupported");
     }
 
@@ -89,35 +143,284 @@ fn base_library_path() -> PathBuf {
 
 #[cfg(all(target_os = "linux", any(target_arch = "arm", target_arch = "aarch64")))]
 fn base_library_path() -> PathBuf {
-    const RPI_MACHINES: [&str; 4] = ["arm11", "cortex-a53", "cortex-a72", "cortex-a76"];
-
     let machine = find_machine_type();
-    match machine.as_str() {
-        machine if RPI_MACHINES.contains(&machine) => {
-            if cfg!(target_arch = "aarch64") {
-                PathBuf::from(format!(
-                    "raspberry-pi/{}-aarch64/libpv_recorder.so",
-                    machine
-                ))
-            } else {
-                PathBuf::from(format!("raspberry-pi/{}/libpv_recorder.so", machine))
-            }
-        }
-        _ => {
-            eprintln!(
-                "WARNING: Device not officially supported by Picovoice. \
-                Falling back to the armv6-based (Raspberry Pi Zero) library. \
-                This is not tested nor optimal. For best results, use Raspberry Pi's models."
-            );
-            PathBuf::from("raspberry-pi/arm11/libpv_recorder.so")
-        }
+    raspberry_pi_base_path(&machine).unwrap_or_else(|| {
+        warn(
+            "Device not officially supported by Picovoice. \
+            Falling back to the armv6-based (Raspberry Pi Zero) library. \
+            This is not tested nor optimal. For best results, use Raspberry Pi's models.",
+        );
+        PathBuf::from("raspberry-pi/arm11/libpv_recorder.so")
+    })
+}
+
+/// Returns the bundled Raspberry Pi library for `machine` (e.g. `cortex-a72`), or
+/// `None` if the machine type is not one of [`RPI_MACHINES`].
+///
+/// The 64-bit variant is chosen when compiled for aarch64; `arm11` only has a 32-bit build.
+fn raspberry_pi_base_path(machine: &str) -> Option<PathBuf> {
+    if !RPI_MACHINES.contains(&machine) {
+        return None;
+    }
+    if cfg!(target_arch = "aarch64") && machine != "arm11" {
+        Some(PathBuf::from(format!(
+            "raspberry-pi/{machine}-aarch64/libpv_recorder.so"
+        )))
+    } else {
+        Some(PathBuf::from(format!(
+            "raspberry-pi/{machine}/libpv_recorder.so"
+        )))
     }
 }
 
+/// Returns the path to the bundled Raspberry Pi library for `machine` and its platform
+/// string, bypassing `/proc/cpuinfo` detection.
+pub(crate) fn pv_arm_library(machine: &str) -> Result<(PathBuf, String), PvRecorderError> {
+    let base_path = raspberry_pi_base_path(machine).ok_or_else(|| {
+        PvRecorderError::new(
+            PvRecorderErrorStatus::ArgumentError,
+            format!(
+                "arm_machine must be one of {}, got: {machine}",
+                RPI_MACHINES.join(", ")
+            ),
+        )
+    })?;
+    let platform = platform_of(&base_path);
+    Ok((resolve_library_path(&base_path), platform))
+}
+
+/// Returns the path to the bundled library for `platform` (e.g. `raspberry-pi/cortex-a72`),
+/// regardless of the host.
+pub(crate) fn pv_platform_library(platform: &str) -> Result<PathBuf, PvRecorderError> {
+    let (_, file_name) = BUNDLED_PLATFORMS
+        .iter()
+        .find(|(name, _)| *name == platform)
+        .ok_or_else(|| {
+            let known: Vec<&str> = BUNDLED_PLATFORMS.iter().map(|(name, _)| *name).collect();
+            PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!(
+                    "platform_override must be one of {}, got: {platform}",
+                    known.join(", ")
+                ),
+            )
+        })?;
+    Ok(resolve_library_path(&Path::new(platform).join(file_name)))
+}
+
+/// Returns the platform string for a base library path, i.e. its directory.
+fn platform_of(base_path: &Path) -> String {
+    base_path
+        .parent()
+        .map(|platform| platform.to_string_lossy().replace('\\', "/"))
+        .unwrap_or_default()
+}
+
+/// Returns the platform string for the host, e.g. `linux/x86_64` or `raspberry-pi/cortex-a72`.
+///
+/// This is the directory of the bundled library selected for the host, so on ARM it
+/// reflects the machine type detected from `/proc/cpuinfo`. It is computed once per
+/// process, so the detection and its warnings do not repeat.
+pub(crate) fn pv_platform() -> &'static str {
+    static PLATFORM: OnceLock<String> = OnceLock::new();
+    PLATFORM.get_or_init(|| platform_of(&base_library_path()))
+}
+
+/// Returns the host's library path under `dir`, a directory laid out like `data/lib`.
+pub(crate) fn pv_library_path_in(dir: &Path) -> PathBuf {
+    dir.join(base_library_path())
+}
+
+/// Directories searched for the library when the build-time copy is missing.
+fn fallback_library_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(exe_dir) = std::env::current_exe()
+        .ok()
+        .and_then(|exe| exe.parent().map(PathBuf::from))
+    {
+        dirs.push(exe_dir);
+    }
+    if cfg!(unix) {
+        dirs.push(PathBuf::from("/usr/local/lib"));
+    }
+    dirs
+}
+
 /// Returns the default path to the pvrecorder library for the current platform.
+///
+/// Prefers the copy placed under `OUT_DIR` at build time. If that file no longer
+/// exists (e.g. the build directory was cleaned or the binary was relocated), the
+/// library file is looked up next to the executable and then in `/usr/local/lib`
+/// (Unix only). If none exists, the `OUT_DIR` path is returned so that loading fails
+/// with the usual error.
+///
+/// The current directory is deliberately not searched: whatever library file is
+/// found gets loaded as native code, so a process started from a writable or
+/// untrusted directory could be made to run a planted library. To load from there,
+/// pass the path explicitly with `PvRecorderBuilder::library_path`.
 #[must_use]
 pub fn pv_library_path() -> PathBuf {
-    PathBuf::from(env!("OUT_DIR"))
+    resolve_library_path(&base_library_path())
+}
+
+/// Resolves a path relative to the bundled library directory, as described for
+/// [`pv_library_path`].
+fn resolve_library_path(base_path: &Path) -> PathBuf {
+    let out_dir_path = PathBuf::from(env!("OUT_DIR"))
         .join(DEFAULT_RELATIVE_LIBRARY_DIR)
-        .join(base_library_path())
+        .join(base_path);
+    if out_dir_path.exists() {
+        return out_dir_path;
+    }
+
+    let Some(file_name) = base_path.file_name() else {
+        return out_dir_path;
+    };
+    fallback_library_dirs()
+        .into_iter()
+        .map(|dir| dir.join(file_name))
+        .find(|path| path.exists())
+        .unwrap_or(out_dir_path)
+}
+
+/// Guesses the audio backend the library ends up on, from the sound servers present.
+///
+/// The library tries Pulse first, then ALSA, so a reachable Pulse socket wins; it is
+/// reported as `pipewire-pulse` when a `pipewire-0` socket sits next to it.
+#[cfg(target_os = "linux")]
+pub(crate) fn detect_audio_backend() -> Option<&'static str> {
+    let runtime_dir = std::env::var_os("XDG_RUNTIME_DIR").map(PathBuf::from);
+    let in_runtime_dir = |name: &str| {
+        runtime_dir
+            .as_ref()
+            .is_some_and(|dir| dir.join(name).exists())
+    };
+
+    if std::env::var_os("PULSE_SERVER").is_some() || in_runtime_dir("pulse/native") {
+        if in_runtime_dir("pipewire-0") {
+            return Some("pipewire-pulse");
+        }
+        return Some("pulseaudio");
+    }
+    if Path::new("/proc/asound").exists() {
+        return Some("alsa");
+    }
+    None
+}
+
+#[cfg(target_os = "macos")]
+pub(crate) fn detect_audio_backend() -> Option<&'static str> {
+    Some("coreaudio")
+}
+
+#[cfg(target_os = "windows")]
+pub(crate) fn detect_audio_backend() -> Option<&'static str> {
+    Some("wasapi")
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+pub(crate) fn detect_audio_backend() -> Option<&'static str> {
+    None
+}
+
+/// Sets an environment variable and restores its previous value when dropped.
+pub(crate) struct EnvVarGuard {
+    key: &'static str,
+    previous: Option<OsString>,
+}
+
+impl EnvVarGuard {
+    pub(crate) fn set(key: &'static str, value: &str) -> Self {
+        let previous = std::env::var_os(key);
+        std::env::set_var(key, value);
+        Self { key, previous }
+    }
+}
+
+impl Drop for EnvVarGuard {
+    fn drop(&mut self) {
+        match &self.previous {
+            Some(previous) => std::env::set_var(self.key, previous),
+            None => std::env::remove_var(self.key),
+        }
+    }
+}
+
+#[cfg(unix)]
+const NULL_DEVICE: &[u8] = b"/dev/null\0";
+#[cfg(windows)]
+const NULL_DEVICE: &[u8] = b"NUL\0";
+const STDERR_FD: c_int = 2;
+
+/// Number of live [`StderrGuard`]s and the saved stderr descriptor, if redirected.
+static STDERR_SILENCE: Mutex<(usize, c_int)> = Mutex::new((0, -1));
+
+/// Redirects the process's stderr (file descriptor 2) to the null device, restoring it
+/// when the last live guard is dropped.
+///
+/// Guards are reference counted, so recorders can silence and restore stderr in any
+/// order.
+pub(crate) struct StderrGuard(());
+
+impl StderrGuard {
+    /// Starts silencing stderr, or returns `None` if it could not be redirected.
+    pub(crate) fn acquire() -> Option<Self> {
+        let mut silence = STDERR_SILENCE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        if silence.0 == 0 {
+            // SAFETY: plain descriptor calls on a NUL-terminated path; every descriptor
+            // opened here is either closed or kept in `STDERR_SILENCE`.
+            unsafe {
+                let null = libc::open(NULL_DEVICE.as_ptr().cast(), libc::O_WRONLY);
+                if null < 0 {
+                    return None;
+                }
+                let saved = libc::dup(STDERR_FD);
+                let redirected = saved >= 0 && libc::dup2(null, STDERR_FD) >= 0;
+                libc::close(null);
+                if !redirected {
+                    if saved >= 0 {
+                        libc::close(saved);
+                    }
+                    return None;
+                }
+                silence.1 = saved;
+            }
+        }
+        silence.0 += 1;
+        Some(Self(()))
+    }
+}
+
+impl Drop for StderrGuard {
+    fn drop(&mut self) {
+        let mut silence = STDERR_SILENCE
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        silence.0 -= 1;
+        if silence.0 == 0 {
+            // SAFETY: `silence.1` is the descriptor saved by `acquire`.
+            unsafe {
+                libc::dup2(silence.1, STDERR_FD);
+                libc::close(silence.1);
+            }
+            silence.1 = -1;
+        }
+    }
+}
+
+/// Converts a duration to a sample count at `sample_rate`, rounding to the nearest sample.
+pub(crate) fn duration_to_samples(duration: Duration, sample_rate: usize) -> usize {
+    let samples = (duration.as_nanos() * sample_rate as u128 + 500_000_000) / 1_000_000_000;
+    usize::try_from(samples).unwrap_or(usize::MAX)
+}
+
+/// Converts a sample count at `sample_rate` to a duration, rounding down to the nanosecond.
+pub(crate) fn samples_to_duration(samples: usize, sample_rate: usize) -> Duration {
+    if sample_rate == 0 {
+        return Duration::ZERO;
+    }
+    let nanos = samples as u128 * 1_000_000_000 / sample_rate as u128;
+    Duration::from_nanos(u64::try_from(nanos).unwrap_or(u64::MAX))
 }
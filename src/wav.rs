@@ -0,0 +1,211 @@
+/*
+    Copyright 2026 Picovoice Inc.
+
+    You may not use this file except in compliance with the license. A copy of the license is located in the "LICENSE"
+    file accompanying this source.
+
+    Unless required by applicable law or agreed to in writing, software distributed under the License is distributed on
+    an "AS IS" BASIS, WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied. See the License for the
+    specific language governing permissions and limitations under the License.
+*/
+
+use std::fs::File;
+use std::io::BufWriter;
+#[cfg(feature = "base64")]
+use std::io::Cursor;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::pvrecorder::{PvRecorder, PvRecorderError, PvRecorderErrorStatus};
+use crate::util::duration_to_samples;
+
+fn wav_error(err: &hound::Error) -> PvRecorderError {
+    PvRecorderError::new(
+        PvRecorderErrorStatus::OtherError,
+        format!("Failed to write WAV file: {err}"),
+    )
+}
+
+/// Returns the 16-bit mono WAV spec for `recorder`'s sample rate.
+fn wav_spec(recorder: &PvRecorder) -> Result<hound::WavSpec, PvRecorderError> {
+    let sample_rate = u32::try_from(recorder.sample_rate()).map_err(|_| {
+        PvRecorderError::new(
+            PvRecorderErrorStatus::ArgumentError,
+            format!(
+                "sample rate {} does not fit in a WAV header",
+                recorder.sample_rate()
+            ),
+        )
+    })?;
+    Ok(hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    })
+}
+
+/// Streams frames from a [`PvRecorder`] into a 16-bit mono WAV file.
+///
+/// Unlike [`RecordingSession`](crate::RecordingSession), frames are written to disk as they
+/// are read, so recording can be interleaved with other work. Call
+/// [`finalize`](Self::finalize) when done to write the final header and observe any
+/// error. Dropping the sink without finalizing still closes the header on a
+/// best-effort basis, so stopping early leaves a valid file.
+///
+/// # Example
+/// ```no_run
+/// use pv_recorder::{PvRecorderBuilder, WavSink};
+///
+/// let recorder = PvRecorderBuilder::default().init()?;
+/// recorder.start()?;
+///
+/// let mut sink = WavSink::create("out.wav", recorder)?;
+/// for _ in 0..100 {
+///     sink.push_frame()?;
+/// }
+/// sink.finalize()?;
+/// # Ok::<(), pv_recorder::PvRecorderError>(())
+/// ```
+pub struct WavSink {
+    recorder: PvRecorder,
+    writer: hound::WavWriter<BufWriter<File>>,
+}
+
+impl WavSink {
+    /// Creates (or truncates) the WAV file at `path` using the recorder's sample rate.
+    ///
+    /// The recorder is not started by this call.
+    pub fn create(path: impl AsRef<Path>, recorder: PvRecorder) -> Result<Self, PvRecorderError> {
+        let spec = wav_spec(&recorder)?;
+        let writer = hound::WavWriter::create(path, spec).map_err(|err| wav_error(&err))?;
+
+        Ok(Self { recorder, writer })
+    }
+
+    /// Reads one frame from the recorder and appends it to the file.
+    ///
+    /// # Errors
+    /// Returns an error if the recorder is not started, a read error occurs, or the
+    /// frame cannot be written.
+    pub fn push_frame(&mut self) -> Result<(), PvRecorderError> {
+        let frame = self.recorder.read()?;
+        let mut writer = self
+            .writer
+            .get_i16_writer(u32::try_from(frame.len()).unwrap_or(u32::MAX));
+        for sample in frame {
+            writer.write_sample(sample);
+        }
+        writer.flush().map_err(|err| wav_error(&err))
+    }
+
+    /// Returns the number of samples written so far.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        usize::try_from(self.writer.len()).unwrap_or(usize::MAX)
+    }
+
+    /// Returns `true` if no samples have been written yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.writer.len() == 0
+    }
+
+    /// Returns the recorder frames are read from.
+    #[must_use]
+    pub fn recorder(&self) -> &PvRecorder {
+        &self.recorder
+    }
+
+    /// Writes the final WAV header and closes the file.
+    ///
+    /// The recorder is left in its current state.
+    pub fn finalize(self) -> Result<(), PvRecorderError> {
+        self.writer.finalize().map_err(|err| wav_error(&err))
+    }
+}
+
+impl PvRecorder {
+    /// Records `duration` of 2-channel audio into two mono WAV files, one per channel.
+    ///
+    /// Each frame is deinterleaved, writing the first channel to `left` and the second
+    /// to `right`. Like [`capture`](Self::capture), this starts the recorder if needed
+    /// and then stops it again only in that case. Requires the `wav` feature.
+    ///
+    /// # Errors
+    /// Returns an `ArgumentError` if the recorder does not have exactly 2
+    /// [`channels`](Self::channels), which is always the case with the current mono
+    /// library, or an error if recording or writing either file fails.
+    pub fn write_stereo_split_wav(
+        &self,
+        left: &Path,
+        right: &Path,
+        duration: Duration,
+    ) -> Result<(), PvRecorderError> {
+        if self.channels() != 2 {
+            return Err(PvRecorderError::new(
+                PvRecorderErrorStatus::ArgumentError,
+                format!(
+                    "write_stereo_split_wav needs a 2-channel recorder, got {} channel(s)",
+                    self.channels()
+                ),
+            ));
+        }
+
+        let spec = wav_spec(self)?;
+        let mut left = hound::WavWriter::create(left, spec).map_err(|err| wav_error(&err))?;
+        let mut right = hound::WavWriter::create(right, spec).map_err(|err| wav_error(&err))?;
+
+        let started = !self.is_recording();
+        if started {
+            self.start()?;
+        }
+
+        let target = duration_to_samples(duration, self.sample_rate());
+        let mut written = 0;
+        let mut result = Ok(());
+        while written < target && result.is_ok() {
+            result = self.read().and_then(|frame| {
+                for pair in frame.chunks_exact(2).take(target - written) {
+                    left.write_sample(pair[0]).map_err(|err| wav_error(&err))?;
+                    right.write_sample(pair[1]).map_err(|err| wav_error(&err))?;
+                    written += 1;
+                }
+                Ok(())
+            });
+        }
+
+        if started {
+            result = result.and(self.stop());
+        }
+        result?;
+        left.finalize().map_err(|err| wav_error(&err))?;
+        right.finalize().map_err(|err| wav_error(&err))
+    }
+}
+
+#[cfg(feature = "base64")]
+impl PvRecorder {
+    /// Records `duration` worth of audio and returns it as a base64-encoded WAV file.
+    ///
+    /// The audio is captured as by [`capture`](Self::capture) and encoded as a 16-bit
+    /// mono WAV in memory, using the standard base64 alphabet with padding.
+    ///
+    /// # Errors
+    /// Returns an error if starting, reading or stopping fails, or the WAV cannot be
+    /// encoded.
+    pub fn capture_wav_base64(&self, duration: Duration) -> Result<String, PvRecorderError> {
+        use base64::Engine;
+
+        let samples = self.capture(duration)?;
+        let mut cursor = Cursor::new(Vec::new());
+        let mut writer =
+            hound::WavWriter::new(&mut cursor, wav_spec(self)?).map_err(|err| wav_error(&err))?;
+        for sample in samples {
+            writer.write_sample(sample).map_err(|err| wav_error(&err))?;
+        }
+        writer.finalize().map_err(|err| wav_error(&err))?;
+
+        Ok(base64::engine::general_purpose::STANDARD.encode(cursor.into_inner()))
+    }
+}
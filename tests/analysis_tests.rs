@@ -0,0 +1,187 @@
+#[cfg(test)]
+mod tests {
+    use pv_recorder::analysis::{
+        apply_gain, decimate, decimate_minmax, downmix_to_mono, f32_to_i16, i16_frames_to_f32,
+        i16_to_f64, pad_to, peak, remove_dc_offset, resample_linear, rms, trim_to, EnergyHistogram,
+    };
+
+    #[test]
+    fn test_i16_to_f64() {
+        assert_eq!(i16_to_f64(&[0, i16::MIN, 16384]), vec![0.0, -1.0, 0.5]);
+    }
+
+    #[test]
+    fn test_remove_dc_offset() {
+        let mut samples = vec![1.0, 2.0, 3.0];
+        remove_dc_offset(&mut samples);
+        assert_eq!(samples, vec![-1.0, 0.0, 1.0]);
+
+        let mut empty: Vec<f64> = Vec::new();
+        remove_dc_offset(&mut empty);
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_downmix_to_mono() {
+        let stereo = [1, 2, -1, -2, i16::MAX, i16::MAX, i16::MIN, i16::MIN, 7];
+        assert_eq!(downmix_to_mono(&stereo, 2), vec![2, -2, i16::MAX, i16::MIN]);
+        assert_eq!(downmix_to_mono(&[1, 2, 3], 1), vec![1, 2, 3]);
+    }
+
+    #[test]
+    fn test_downmix_to_mono_rounding_and_extremes() {
+        let stereo = [1, 2, -1, -2, i16::MAX, i16::MIN, i16::MIN, i16::MAX, 0, 1];
+        assert_eq!(downmix_to_mono(&stereo, 2), vec![2, -2, -1, -1, 1]);
+
+        let three = [
+            i16::MAX,
+            i16::MAX,
+            i16::MAX,
+            i16::MIN,
+            i16::MIN,
+            i16::MIN,
+            1,
+            1,
+            2,
+        ];
+        assert_eq!(downmix_to_mono(&three, 3), vec![i16::MAX, i16::MIN, 1]);
+        assert!(downmix_to_mono(&[5], 2).is_empty());
+        assert_eq!(downmix_to_mono(&[4, -4], 0), vec![4, -4]);
+    }
+
+    #[test]
+    fn test_peak_and_apply_gain() {
+        assert_eq!(peak(&[]), 0);
+        assert_eq!(peak(&[3, -7, 5]), 7);
+        assert_eq!(peak(&[i16::MIN]), i16::MAX);
+
+        let mut samples = vec![100, -100, 20000];
+        apply_gain(&mut samples, 2.0);
+        assert_eq!(samples, vec![200, -200, i16::MAX]);
+    }
+
+    #[cfg(feature = "spectrum")]
+    #[test]
+    fn test_frame_spectrum() {
+        use pv_recorder::analysis::{frame_spectrum, spectrum_bin_frequency};
+
+        // Full-scale 1 kHz tone at 16 kHz over 512 samples lands on bin 32.
+        let samples: Vec<i16> = (0..512)
+            .map(|n| {
+                let t = f64::from(n) / 16000.0;
+                #[allow(clippy::cast_possible_truncation)]
+                let sample = (32767.0 * (std::f64::consts::TAU * 1000.0 * t).sin()).round() as i16;
+                sample
+            })
+            .collect();
+
+        let spectrum = frame_spectrum(&samples);
+        assert_eq!(spectrum.len(), 257);
+        let (peak_bin, peak) = spectrum
+            .iter()
+            .enumerate()
+            .max_by(|a, b| a.1.total_cmp(b.1))
+            .expect("empty spectrum");
+        assert_eq!(peak_bin, 32);
+        assert!((peak - 1.0).abs() < 0.01, "peak magnitude was {peak}");
+        assert!((spectrum_bin_frequency(peak_bin, 512, 16000) - 1000.0).abs() < f32::EPSILON);
+
+        assert!(frame_spectrum(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_decimate_minmax() {
+        let samples = [1, -4, 3, 7, -2, 0];
+        assert_eq!(decimate_minmax(&samples, 2), vec![(-4, 3), (-2, 7)]);
+        assert_eq!(decimate_minmax(&samples, 4).len(), 4);
+        assert_eq!(decimate_minmax(&samples[..2], 10), vec![(1, 1), (-4, -4)]);
+        assert!(decimate_minmax(&samples, 0).is_empty());
+        assert!(decimate_minmax(&[], 4).is_empty());
+    }
+
+    #[test]
+    fn test_resample_linear() {
+        assert_eq!(
+            resample_linear(&[0, 100, 200, 300], 16000, 8000),
+            vec![0, 200]
+        );
+        assert_eq!(
+            resample_linear(&[0, 100, 200], 8000, 16000),
+            vec![0, 50, 100, 150, 200, 200]
+        );
+        assert_eq!(resample_linear(&[1, 2, 3], 16000, 16000), vec![1, 2, 3]);
+        assert!(resample_linear(&[1, 2, 3], 0, 16000).is_empty());
+        assert!(resample_linear(&[], 16000, 8000).is_empty());
+    }
+
+    #[test]
+    fn test_trim_and_pad() {
+        assert_eq!(trim_to(vec![1, 2, 3], 2), vec![1, 2]);
+        assert_eq!(trim_to(vec![1, 2], 5), vec![1, 2]);
+
+        assert_eq!(pad_to(vec![1, 2], 4, 0), vec![1, 2, 0, 0]);
+        assert_eq!(pad_to(vec![1, 2, 3], 2, 0), vec![1, 2]);
+        assert_eq!(pad_to(Vec::new(), 2, -1), vec![-1, -1]);
+    }
+
+    #[test]
+    fn test_f32_round_trip() {
+        let samples = [i16::MIN, -16384, 0, 16384, i16::MAX];
+        let converted = i16_frames_to_f32(&samples);
+        assert!((converted[0] + 1.0).abs() < f32::EPSILON);
+        assert!((converted[1] + 0.5).abs() < f32::EPSILON);
+        assert!((converted[3] - 0.5).abs() < f32::EPSILON);
+        assert_eq!(f32_to_i16(&converted), samples.to_vec());
+
+        assert_eq!(
+            f32_to_i16(&[1.0, 2.0, -2.0, f32::NAN]),
+            vec![i16::MAX, i16::MAX, i16::MIN, 0]
+        );
+    }
+
+    #[test]
+    fn test_decimate() {
+        assert_eq!(decimate(&[0, 2, 4, 6, 8], 2), vec![1, 5, 8]);
+        assert_eq!(decimate(&[-1, -2, 1, 2], 2), vec![-2, 2]);
+        assert_eq!(decimate(&[i16::MAX, i16::MAX, i16::MIN], 3), vec![10922]);
+        assert_eq!(decimate(&[1, 2, 3], 1), vec![1, 2, 3]);
+        assert!(decimate(&[1, 2, 3], 0).is_empty());
+        assert!(decimate(&[], 2).is_empty());
+    }
+
+    #[test]
+    fn test_rms() {
+        assert!(rms(&[]).abs() < f32::EPSILON);
+        assert!(rms(&[0; 16]).abs() < f32::EPSILON);
+        assert!((rms(&[i16::MIN, i16::MIN]) - 1.0).abs() < f32::EPSILON);
+        assert!((rms(&[16384, -16384]) - 0.5).abs() < f32::EPSILON);
+    }
+
+    #[test]
+    fn test_energy_histogram() {
+        let mut histogram = EnergyHistogram::new();
+        assert!(histogram.percentile(50.0).abs() < f32::EPSILON);
+
+        // Eight quiet frames (about -40 dBFS) and two loud ones (about -6 dBFS).
+        for _ in 0..8 {
+            histogram.observe(&[328, -328]);
+        }
+        histogram.observe(&[16384, -16384]);
+        histogram.observe(&[16384, -16384]);
+        histogram.observe(&[0, 0]);
+        assert_eq!(histogram.count(), 11);
+
+        // Digital silence lands in the lowest bucket.
+        assert!(histogram.percentile(0.0) < 0.0001);
+        let quiet = histogram.percentile(50.0);
+        assert!(
+            quiet >= rms(&[328, -328]) && quiet < 0.0107,
+            "quiet was {quiet}"
+        );
+        let loud = histogram.percentile(100.0);
+        assert!((0.5..0.53).contains(&loud), "loud was {loud}");
+
+        histogram.clear();
+        assert_eq!(histogram.count(), 0);
+    }
+}
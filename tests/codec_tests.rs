@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use pv_recorder::{frame_from_le_bytes, frame_to_le_bytes, PvRecorderErrorStatus};
+
+    #[test]
+    fn test_frame_to_le_bytes_layout() {
+        let bytes = frame_to_le_bytes(&[0x0102, -2]);
+        assert_eq!(bytes, vec![0x02, 0x01, 0xFE, 0xFF]);
+    }
+
+    #[test]
+    fn test_frame_bytes_round_trip() {
+        let samples = vec![0, 1, -1, i16::MAX, i16::MIN, 12345];
+        let bytes = frame_to_le_bytes(&samples);
+        assert_eq!(bytes.len(), samples.len() * 2);
+        assert_eq!(frame_from_le_bytes(&bytes).unwrap(), samples);
+    }
+
+    #[test]
+    fn test_frame_from_odd_length_bytes() {
+        let result = frame_from_le_bytes(&[0x00, 0x01, 0x02]);
+        assert!(result.is_err());
+
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+    }
+}
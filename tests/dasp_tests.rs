@@ -0,0 +1,29 @@
+#![cfg(feature = "dasp")]
+
+#[cfg(test)]
+mod tests {
+    use dasp::Signal;
+    use pv_recorder::{frame_signal, PvRecorderBuilder, PvRecorderError};
+
+    #[test]
+    fn test_frame_signal() {
+        let mut signal = frame_signal(vec![1, -2, 3]);
+        assert_eq!(signal.next(), 1);
+        assert_eq!(signal.next(), -2);
+        assert_eq!(signal.next(), 3);
+        assert!(signal.is_exhausted());
+    }
+
+    #[test]
+    fn test_read_dasp() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+        let frame: Vec<f32> = recorder.read_dasp()?;
+        recorder.stop()?;
+
+        assert_eq!(frame.len(), 512);
+        assert!(frame.iter().all(|sample| (-1.0..1.0).contains(sample)));
+
+        Ok(())
+    }
+}
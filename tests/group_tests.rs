@@ -0,0 +1,28 @@
+#[cfg(test)]
+mod tests {
+    use pv_recorder::{PvRecorderBuilder, PvRecorderError, PvRecorderErrorStatus, PvRecorderGroup};
+
+    #[test]
+    fn test_group_read_all() -> Result<(), PvRecorderError> {
+        let group = PvRecorderGroup::new(&[0, 0], &PvRecorderBuilder::new(512))?;
+        assert_eq!(group.recorders().len(), 2);
+
+        group.start()?;
+        let frames = group.read_all()?;
+        group.stop()?;
+
+        assert_eq!(frames.len(), 2);
+        assert!(frames.iter().all(|frame| frame.len() == 512));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_group_empty() {
+        let result = PvRecorderGroup::new(&[], &PvRecorderBuilder::new(512));
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+    }
+}
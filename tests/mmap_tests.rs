@@ -0,0 +1,29 @@
+#![cfg(feature = "mmap")]
+
+#[cfg(test)]
+mod tests {
+    use pv_recorder::{PvRecorderBuilder, PvRecorderError, PvRecorderErrorStatus};
+
+    #[test]
+    fn test_record_to_mmap() -> Result<(), PvRecorderError> {
+        let path = std::env::temp_dir().join("pv_recorder_mmap_test.pcm");
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+
+        let result = recorder.record_to_mmap(&path, 1000);
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        recorder.start()?;
+        let written = recorder.record_to_mmap(&path, 1000)?;
+        recorder.stop()?;
+        assert_eq!(written, 1000);
+
+        let bytes = std::fs::read(&path).expect("failed to read mapped file");
+        assert_eq!(bytes.len(), 2000);
+
+        let _ = std::fs::remove_file(&path);
+        Ok(())
+    }
+}
@@ -0,0 +1,44 @@
+#[cfg(test)]
+mod tests {
+    use pv_recorder::{OverlapBuffer, PvRecorderError, PvRecorderErrorStatus};
+
+    #[test]
+    fn test_overlapping_windows() -> Result<(), PvRecorderError> {
+        let mut buffer = OverlapBuffer::new(4, 2)?;
+        buffer.push(&[0, 1, 2]);
+        assert_eq!(buffer.next_window(), None);
+
+        // A frame length of 3 does not divide the hop of 2.
+        buffer.push(&[3, 4, 5]);
+        assert_eq!(buffer.next_window(), Some(vec![0, 1, 2, 3]));
+        assert_eq!(buffer.next_window(), Some(vec![2, 3, 4, 5]));
+        assert_eq!(buffer.next_window(), None);
+        assert_eq!(buffer.buffered(), 2);
+
+        buffer.clear();
+        assert_eq!(buffer.buffered(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_hop_larger_than_window() -> Result<(), PvRecorderError> {
+        let mut buffer = OverlapBuffer::new(2, 5)?;
+        buffer.push(&[0, 1, 2]);
+        assert_eq!(buffer.next_window(), Some(vec![0, 1]));
+        buffer.push(&[3, 4, 5, 6]);
+        assert_eq!(buffer.next_window(), Some(vec![5, 6]));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_invalid_arguments() {
+        let result = OverlapBuffer::new(0, 1);
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+        assert!(OverlapBuffer::new(4, 0).is_err());
+    }
+}
@@ -17,8 +17,8 @@ mod tests {
     fn test_init() -> Result<(), PvRecorderError> {
         let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
         assert!(recorder.sample_rate() > 0);
-        assert!(recorder.selected_device().len() > 0);
-        assert!(recorder.version().len() > 0);
+        assert!(!recorder.selected_device().is_empty());
+        assert!(!recorder.version().is_empty());
 
         Ok(())
     }
@@ -33,15 +33,15 @@ mod tests {
             .init()?;
         recorder.set_debug_logging(true);
 
-        assert!(recorder.is_recording() == false);
+        assert!(!recorder.is_recording());
         recorder.start()?;
-        assert!(recorder.is_recording() == true);
+        assert!(recorder.is_recording());
 
         let frame = recorder.read()?;
-        assert!(frame.len() == frame_length as usize);
+        assert!(frame.len() == usize::try_from(frame_length).unwrap());
 
         recorder.stop()?;
-        assert!(recorder.is_recording() == false);
+        assert!(!recorder.is_recording());
 
         Ok(())
     }
@@ -51,7 +51,7 @@ mod tests {
         let devices = PvRecorderBuilder::default().get_available_devices()?;
 
         for device in devices {
-            assert!(device.len() >= 0)
+            assert!(!device.is_empty());
         }
 
         Ok(())
@@ -1,6 +1,9 @@
 #[cfg(test)]
 mod tests {
-    use pv_recorder::{PvRecorderBuilder, PvRecorderError, PvRecorderErrorStatus};
+    use pv_recorder::{
+        DeviceWatcher, PvRecorderBuilder, PvRecorderError, PvRecorderErrorStatus, PvRecorderStatus,
+        RecordingSession,
+    };
 
     #[test]
     fn test_init() -> Result<(), PvRecorderError> {
@@ -29,7 +32,7 @@ mod tests {
         assert!(recorder.is_recording());
 
         let frame = recorder.read()?;
-        assert_eq!(frame.len(), frame_length as usize);
+        assert_eq!(frame.len(), usize::try_from(frame_length).unwrap());
 
         recorder.stop()?;
         assert!(!recorder.is_recording());
@@ -48,11 +51,11 @@ mod tests {
         recorder.start()?;
 
         // Test read_into with exact size buffer
-        let mut buffer = vec![0i16; frame_length as usize];
+        let mut buffer = vec![0i16; usize::try_from(frame_length).unwrap()];
         recorder.read_into(&mut buffer)?;
         
         // Test read_into with larger buffer (should work)
-        let mut large_buffer = vec![0i16; frame_length as usize * 2];
+        let mut large_buffer = vec![0i16; usize::try_from(frame_length).unwrap() * 2];
         recorder.read_into(&mut large_buffer)?;
 
         recorder.stop()?;
@@ -148,7 +151,7 @@ mod tests {
     #[test]
     fn test_frame_length_getter() -> Result<(), PvRecorderError> {
         let expected_frame_length = 1024usize;
-        let recorder = PvRecorderBuilder::new(expected_frame_length as i32)
+        let recorder = PvRecorderBuilder::new(i32::try_from(expected_frame_length).unwrap())
             .device_index(0)
             .init()?;
         
@@ -165,8 +168,8 @@ mod tests {
         
         let sample_rate = recorder.sample_rate();
         // Common sample rates are 8000, 16000, 22050, 44100, 48000
-        assert!(sample_rate >= 8000, "Sample rate {} is too low", sample_rate);
-        assert!(sample_rate <= 96000, "Sample rate {} is too high", sample_rate);
+        assert!(sample_rate >= 8000, "Sample rate {sample_rate} is too low");
+        assert!(sample_rate <= 96000, "Sample rate {sample_rate} is too high");
         
         Ok(())
     }
@@ -180,10 +183,15 @@ mod tests {
         let recorder2 = recorder1.clone();
         
         // Both should report same state
-        assert_eq!(recorder1.frame_length(), recorder2.frame_length());
-        assert_eq!(recorder1.sample_rate(), recorder2.sample_rate());
-        assert_eq!(recorder1.selected_device(), recorder2.selected_device());
-        assert_eq!(recorder1.version(), recorder2.version());
+        assert_eq!(recorder1, recorder2);
+        assert!(recorder1.same_config(&recorder2));
+
+        let recorder3 = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert_ne!(recorder1, recorder3);
+        assert!(recorder1.same_config(&recorder3));
+
+        let recorder4 = PvRecorderBuilder::new(256).device_index(0).init()?;
+        assert!(!recorder1.same_config(&recorder4));
         
         Ok(())
     }
@@ -195,8 +203,1532 @@ mod tests {
             "test error message",
         );
         
-        let display = format!("{}", err);
+        let display = format!("{err}");
         assert!(display.contains("test error message"));
         assert!(display.contains("ArgumentError"));
     }
+
+    #[test]
+    fn test_device_watcher_poll_without_changes() -> Result<(), PvRecorderError> {
+        let mut watcher = DeviceWatcher::new(PvRecorderBuilder::default())?;
+        let snapshot = watcher.devices().to_vec();
+
+        let change = watcher.poll()?;
+        assert!(change.is_empty());
+        assert_eq!(watcher.devices(), snapshot.as_slice());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_timed() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+
+        recorder.start()?;
+        let (frame, elapsed) = recorder.read_timed()?;
+        recorder.stop()?;
+
+        assert_eq!(frame.len(), recorder.frame_length());
+        assert!(elapsed < std::time::Duration::from_secs(5));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_builder_presets() -> Result<(), PvRecorderError> {
+        let low_latency = PvRecorderBuilder::default()
+            .low_latency()
+            .device_index(0)
+            .init()?;
+        assert_eq!(low_latency.frame_length(), 256);
+
+        let high_stability = PvRecorderBuilder::default()
+            .high_stability()
+            .device_index(0)
+            .init()?;
+        assert_eq!(high_stability.frame_length(), 1024);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_snapshot() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+
+        let status = recorder.status();
+        assert_eq!(status.frame_length, 512);
+        assert_eq!(status.device_index, 0);
+        assert_eq!(status.sample_rate, recorder.sample_rate());
+        assert_eq!(status.selected_device, recorder.selected_device());
+        assert!(!status.is_recording);
+        assert_eq!(status.samples_read, 0);
+
+        recorder.start()?;
+        recorder.read()?;
+        recorder.stop()?;
+        assert_eq!(recorder.status().samples_read, 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_auto_recover_read() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .auto_recover(3)
+            .init()?;
+
+        recorder.start()?;
+        let frame = recorder.read()?;
+        recorder.stop()?;
+
+        assert_eq!(frame.len(), 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_as_map_key() {
+        let mut counts = std::collections::HashMap::new();
+        *counts.entry(PvRecorderStatus::IO_ERROR).or_insert(0u64) += 1;
+        *counts.entry(PvRecorderStatus::IO_ERROR).or_insert(0u64) += 1;
+        *counts
+            .entry(PvRecorderStatus::BACKEND_ERROR)
+            .or_insert(0u64) += 1;
+        assert_eq!(counts[&PvRecorderStatus::IO_ERROR], 2);
+
+        let mut statuses: Vec<_> = counts.keys().copied().collect();
+        statuses.sort();
+        assert_eq!(
+            statuses,
+            vec![PvRecorderStatus::BACKEND_ERROR, PvRecorderStatus::IO_ERROR]
+        );
+    }
+
+    #[test]
+    fn test_read_boxed() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+
+        recorder.start()?;
+        let frame = recorder.read_boxed()?;
+        recorder.stop()?;
+
+        assert_eq!(frame.len(), 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_debug_logging() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert!(!recorder.is_debug_logging());
+
+        recorder.set_debug_logging(true);
+        assert!(recorder.is_debug_logging());
+
+        recorder.set_debug_logging(false);
+        assert!(!recorder.is_debug_logging());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recording_session() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+
+        let mut session = RecordingSession::new(
+            recorder.clone(),
+            Some(std::time::Duration::from_millis(100)),
+        );
+        assert!(session.is_empty());
+        session.capture_frame()?;
+        session.capture_frame()?;
+        recorder.stop()?;
+
+        assert_eq!(session.len(), 1024);
+        assert_eq!(session.finish().len(), 1024);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_read_into() -> Result<(), PvRecorderError> {
+        let frame_length = 512;
+
+        let recorder = PvRecorderBuilder::new(frame_length)
+            .device_index(0)
+            .init()?;
+        recorder.start()?;
+
+        let mut buffer = vec![0i16; usize::try_from(frame_length).unwrap()];
+        recorder.try_read_into(&mut buffer)?;
+
+        let mut small_buffer = vec![0i16; 100];
+        let result = recorder.try_read_into(&mut small_buffer);
+        recorder.stop()?;
+
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+            assert!(err.message().contains("buffer length"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_device_option() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device(Some(0)).init()?;
+        assert_eq!(recorder.device_index(), 0);
+
+        let recorder = PvRecorderBuilder::new(512).device(None).init();
+        if let Ok(recorder) = recorder {
+            assert_eq!(recorder.device_index(), -1);
+        }
+
+        let result = PvRecorderBuilder::new(512).device(Some(usize::MAX)).init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+            assert!(err.message().contains("device_index"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_health_check() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let timeout = std::time::Duration::from_secs(1);
+
+        assert!(!recorder.health_check(timeout)?);
+
+        recorder.start()?;
+        let _healthy = recorder.health_check(timeout)?;
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_health_check_strict_single_thread() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .strict_single_thread(true)
+            .init()?;
+        recorder.start()?;
+
+        let result = recorder.health_check(std::time::Duration::from_secs(1));
+        let frame = recorder.read();
+        recorder.stop()?;
+
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::OtherError));
+            assert!(err.message().contains("strict_single_thread"));
+        }
+        assert_eq!(frame?.len(), 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_f64_dc_removed() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+
+        recorder.start()?;
+        let samples = recorder.read_f64_dc_removed()?;
+        recorder.stop()?;
+
+        assert_eq!(samples.len(), 512);
+        let mean = samples.iter().sum::<f64>() / 512.0;
+        assert!(mean.abs() < 1e-9);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_audio_devices() -> Result<(), PvRecorderError> {
+        let builder = PvRecorderBuilder::default();
+        let names = builder.get_available_devices()?;
+        let devices = builder.get_audio_devices()?;
+
+        assert_eq!(devices.len(), names.len());
+        for (i, device) in devices.iter().enumerate() {
+            assert_eq!(device.index, i32::try_from(i).unwrap());
+            assert_eq!(device.name, names[i]);
+        }
+
+        Ok(())
+    }
+
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    #[test]
+    fn test_builder_is_send_sync() {
+        assert_send_sync::<PvRecorderBuilder>();
+    }
+
+    #[test]
+    fn test_select_device() -> Result<(), PvRecorderError> {
+        let devices = PvRecorderBuilder::default().get_audio_devices()?;
+
+        if let Some(first) = devices.first() {
+            let name = first.name.clone();
+            let recorder = PvRecorderBuilder::new(512)
+                .select_device(move |device| device.name == name)
+                .init()?;
+            assert_eq!(recorder.device_index(), first.index);
+        }
+
+        let result = PvRecorderBuilder::new(512).select_device(|_| false).init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_select_device_skips_default_probe() -> Result<(), PvRecorderError> {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let saw_default = Arc::new(AtomicBool::new(false));
+        let seen = Arc::clone(&saw_default);
+        let recorder = PvRecorderBuilder::new(512)
+            .select_device(move |device| {
+                seen.fetch_or(device.is_default, Ordering::Relaxed);
+                device.index == 0
+            })
+            .init()?;
+
+        assert_eq!(recorder.device_index(), 0);
+        assert!(!saw_default.load(Ordering::Relaxed));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_planar() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert_eq!(recorder.channels(), 1);
+
+        recorder.start()?;
+        let mut planes = vec![Vec::new()];
+        recorder.read_planar(&mut planes)?;
+        let mismatched = recorder.read_planar(&mut [Vec::new(), Vec::new()]);
+        recorder.stop()?;
+
+        assert_eq!(planes[0].len(), 512);
+        assert!(mismatched.is_err());
+        if let Err(err) = mismatched {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_setters() {
+        assert!(PvRecorderBuilder::default().try_frame_length(256).is_ok());
+        assert!(PvRecorderBuilder::default().try_device_index(-1).is_ok());
+        assert!(PvRecorderBuilder::default()
+            .try_buffered_frames_count(10)
+            .is_ok());
+
+        let invalid = [
+            PvRecorderBuilder::default().try_frame_length(0).err(),
+            PvRecorderBuilder::default().try_device_index(-2).err(),
+            PvRecorderBuilder::default()
+                .try_buffered_frames_count(0)
+                .err(),
+        ];
+        for err in invalid {
+            let err = err.expect("invalid value should be rejected");
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+    }
+
+    #[test]
+    fn test_platform() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+
+        let platform = recorder.platform();
+        assert!(!platform.is_empty());
+        assert!(!platform.contains('\\'));
+        assert!(platform.contains('/'));
+
+        let switched = recorder.switch_device(0)?;
+        assert_eq!(switched.platform(), platform);
+        switched.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_exact_samples() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+
+        recorder.start()?;
+        let first = recorder.read_exact_samples(100)?;
+        let second = recorder.read_exact_samples(1000)?;
+        let empty = recorder.read_exact_samples(0)?;
+        recorder.stop()?;
+
+        assert_eq!(first.len(), 100);
+        assert_eq!(second.len(), 1000);
+        assert!(empty.is_empty());
+        // 100 + 1000 samples need three frames.
+        assert_eq!(recorder.samples_read(), 3 * 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_downmix_to_mono_read() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .downmix_to_mono(true)
+            .init()?;
+
+        recorder.start()?;
+        let frame = recorder.read()?;
+        recorder.stop()?;
+
+        assert_eq!(frame.len(), recorder.frame_length());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_available_devices_free_functions() -> Result<(), PvRecorderError> {
+        let builder = PvRecorderBuilder::default();
+        assert_eq!(
+            pv_recorder::available_devices()?,
+            builder.get_available_devices()?
+        );
+        assert_eq!(
+            pv_recorder::available_audio_devices()?,
+            builder.get_audio_devices()?
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_expected_sample_rate() -> Result<(), PvRecorderError> {
+        let sample_rate = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .init()?
+            .sample_rate();
+
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .expected_sample_rate(sample_rate)
+            .init()?;
+        assert_eq!(recorder.sample_rate(), sample_rate);
+
+        let result = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .expected_sample_rate(sample_rate + 1)
+            .init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_frame() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert!(recorder.last_frame().is_empty());
+
+        recorder.start()?;
+        let frame = recorder.read()?;
+        recorder.stop()?;
+
+        assert_eq!(recorder.last_frame(), frame);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_capture() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let duration = std::time::Duration::from_millis(100);
+        let expected_len = recorder.sample_rate() / 10;
+
+        let samples = recorder.capture(duration)?;
+        assert_eq!(samples.len(), expected_len);
+        assert!(!recorder.is_recording());
+
+        recorder.start()?;
+        let samples = recorder.capture(duration)?;
+        assert_eq!(samples.len(), expected_len);
+        assert!(recorder.is_recording());
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_gain() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .normalize_gain(0.5)
+            .init()?;
+        assert!((recorder.last_gain() - 1.0).abs() < f32::EPSILON);
+
+        recorder.start()?;
+        recorder.read()?;
+        recorder.stop()?;
+
+        let gain = recorder.last_gain();
+        assert!(gain > 0.0 && gain <= 10.0);
+
+        let result = PvRecorderBuilder::new(512).normalize_gain(1.5).init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_capture_with_progress() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let duration = std::time::Duration::from_millis(100);
+        let expected_len = recorder.sample_rate() / 10;
+
+        let mut updates = Vec::new();
+        let samples = recorder.capture_with_progress(duration, |progress| {
+            updates.push(*progress);
+        })?;
+        assert_eq!(samples.len(), expected_len);
+        assert_eq!(updates.len(), (expected_len + 511) / 512);
+
+        let last = updates.last().expect("no progress reported");
+        assert_eq!(last.captured_samples(), expected_len);
+        assert_eq!(last.target_samples(), expected_len);
+        assert_eq!(last.elapsed(), duration);
+        assert_eq!(last.target(), duration);
+        assert!((last.fraction() - 1.0).abs() < f64::EPSILON);
+        assert!(updates[0].fraction() < 1.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_available_devices_load_error() {
+        let result = PvRecorderBuilder::default()
+            .library_path(std::path::Path::new("does/not/exist.so"))
+            .get_available_devices();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(
+                err.status(),
+                PvRecorderErrorStatus::LibraryLoadError
+            ));
+        }
+    }
+
+    #[test]
+    fn test_arm_machine_unknown() {
+        let result = PvRecorderBuilder::default()
+            .arm_machine("cortex-z9")
+            .get_available_devices();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        // A known machine selects its bundled library, which may not load on this host.
+        let result = PvRecorderBuilder::default()
+            .arm_machine("cortex-a72")
+            .get_available_devices();
+        if let Err(err) = result {
+            assert!(matches!(
+                err.status(),
+                PvRecorderErrorStatus::LibraryLoadError
+            ));
+        }
+    }
+
+    #[test]
+    fn test_reset_counters() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+        recorder.read()?;
+        assert_eq!(recorder.samples_read(), 512);
+
+        recorder.reset_counters();
+        assert_eq!(recorder.samples_read(), 0);
+        assert!(recorder.is_recording());
+
+        recorder.read()?;
+        assert_eq!(recorder.samples_read(), 512);
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_platform_override() -> Result<(), PvRecorderError> {
+        let result = PvRecorderBuilder::default()
+            .platform_override("amiga/m68k")
+            .get_available_devices();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        let host = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .platform_override(host.platform())
+            .init()?;
+        assert_eq!(recorder.platform(), host.platform());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_snapshot() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+        recorder.read()?;
+
+        let snapshot = recorder.snapshot();
+        assert_eq!(snapshot.frame_length, 512);
+        assert_eq!(snapshot.sample_rate as usize, recorder.sample_rate());
+        assert_eq!(snapshot.device_index, 0);
+        assert_eq!(snapshot.is_recording, 1);
+        assert_eq!(snapshot.samples_read, 512);
+        assert!(recorder
+            .selected_device()
+            .starts_with(snapshot.selected_device()));
+
+        recorder.stop()?;
+        assert_eq!(recorder.snapshot().is_recording, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_discard_buffered() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.discard_buffered()?;
+        assert!(!recorder.is_recording());
+
+        recorder.start()?;
+        recorder.read_exact_samples(100)?;
+        recorder.discard_buffered()?;
+        assert!(recorder.is_recording());
+        assert_eq!(recorder.read_exact_samples(100)?.len(), 100);
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_stamped() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert!(recorder.first_frame_instant().is_none());
+
+        recorder.start()?;
+        let before = std::time::Instant::now();
+        let (frame, first) = recorder.read_stamped()?;
+        let (_, second) = recorder.read_stamped()?;
+        recorder.stop()?;
+
+        assert_eq!(frame.len(), 512);
+        assert!(first >= before);
+        assert!(second >= first);
+        assert_eq!(recorder.first_frame_instant(), Some(first));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stop_if_recording() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.stop_if_recording()?;
+
+        recorder.start()?;
+        recorder.stop_if_recording()?;
+        assert!(!recorder.is_recording());
+        recorder.stop_if_recording()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_preferred_frame_length() {
+        let builder = PvRecorderBuilder::default();
+        assert_eq!(builder.preferred_frame_length(0), None);
+        assert_eq!(builder.preferred_frame_length(-1), None);
+    }
+
+    #[test]
+    fn test_recommended_buffered_frames_count() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .buffered_frames_count(2)
+            .init()?;
+        assert_eq!(recorder.buffered_frames_count(), 2);
+        assert_eq!(recorder.recommended_buffered_frames_count(), 2);
+
+        recorder.start()?;
+        recorder.read()?;
+        std::thread::sleep(std::time::Duration::from_millis(200));
+        recorder.read()?;
+        recorder.stop()?;
+
+        // 200 ms is more than 6 frames of 512 samples at 16 kHz.
+        assert!(recorder.max_read_gap() >= std::time::Duration::from_millis(200));
+        assert!(recorder.recommended_buffered_frames_count() >= 12);
+
+        recorder.reset_counters();
+        assert_eq!(recorder.max_read_gap(), std::time::Duration::ZERO);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_device_index_out_of_range() -> Result<(), PvRecorderError> {
+        let count = PvRecorderBuilder::default().get_available_devices()?.len();
+        let out_of_range = i32::try_from(count).unwrap_or(i32::MAX);
+
+        let result = PvRecorderBuilder::new(512)
+            .device_index(out_of_range)
+            .init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+            assert!(err.message().contains(&format!("0..{count}")));
+        }
+
+        // The default device may be unavailable on this host.
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(out_of_range)
+            .clamp_device_index(true)
+            .init();
+        match recorder {
+            Ok(recorder) => assert_eq!(recorder.device_index(), -1),
+            Err(err) => assert!(matches!(
+                err.status(),
+                PvRecorderErrorStatus::LibraryError(_)
+            )),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_switch_device() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(256).device_index(0).init()?;
+        recorder.start()?;
+
+        let switched = recorder.switch_device(0)?;
+        assert!(switched.is_recording());
+        assert!(recorder.is_recording());
+        assert_eq!(switched.frame_length(), 256);
+        assert_eq!(switched.device_index(), 0);
+        assert_eq!(switched.read()?.len(), 256);
+
+        recorder.stop()?;
+        switched.stop()?;
+
+        let result = recorder.switch_device(i32::MAX);
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_switch_device_keeps_options() -> Result<(), PvRecorderError> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let frames_seen = Arc::new(AtomicUsize::new(0));
+        let observer_count = Arc::clone(&frames_seen);
+        let recorder = PvRecorderBuilder::new(256)
+            .device_index(0)
+            .strict_single_thread(true)
+            .on_frame(Arc::new(move |_: &[i16]| {
+                observer_count.fetch_add(1, Ordering::Relaxed);
+            }))
+            .init()?;
+
+        let switched = recorder.switch_device(0)?;
+        switched.read()?;
+        let from_other_thread =
+            std::thread::scope(|scope| scope.spawn(|| switched.read().is_err()).join().unwrap());
+        switched.stop()?;
+
+        assert_eq!(frames_seen.load(Ordering::Relaxed), 1);
+        assert!(from_other_thread);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_try_set_debug_logging() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.try_set_debug_logging(true)?;
+        assert!(recorder.is_debug_logging());
+        recorder.try_set_debug_logging(false)?;
+        assert!(!recorder.is_debug_logging());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recording_duration() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert_eq!(recorder.recording_duration(), std::time::Duration::ZERO);
+
+        recorder.start()?;
+        recorder.read()?;
+        recorder.read()?;
+        // 1024 samples at 16 kHz.
+        assert_eq!(
+            recorder.recording_duration(),
+            std::time::Duration::from_millis(64)
+        );
+        recorder.stop()?;
+
+        recorder.start()?;
+        assert_eq!(recorder.recording_duration(), std::time::Duration::ZERO);
+        assert_eq!(recorder.samples_read(), 1024);
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_library_dir() -> Result<(), PvRecorderError> {
+        let library_dir = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("data/lib");
+        let devices = PvRecorderBuilder::default()
+            .library_dir(&library_dir)
+            .get_available_devices()?;
+        assert_eq!(
+            devices,
+            PvRecorderBuilder::default().get_available_devices()?
+        );
+
+        let result = PvRecorderBuilder::default()
+            .library_dir(std::path::Path::new("does/not/exist"))
+            .get_available_devices();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(
+                err.status(),
+                PvRecorderErrorStatus::LibraryLoadError
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_record_until() -> Result<(), PvRecorderError> {
+        use std::sync::atomic::{AtomicBool, Ordering};
+        use std::sync::Arc;
+
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let stop = Arc::new(AtomicBool::new(false));
+
+        // Not recording: returns immediately.
+        assert_eq!(recorder.record_until(&stop, |_| {})?, 0);
+
+        recorder.start()?;
+        let mut seen = 0;
+        let frames = recorder.record_until(&stop, |frame| {
+            assert_eq!(frame.len(), 512);
+            seen += 1;
+            if seen == 3 {
+                stop.store(true, Ordering::Relaxed);
+            }
+        })?;
+        recorder.stop()?;
+        assert_eq!(frames, 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolved_vs_requested() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert_eq!(recorder.resolved_device_index(), 0);
+        assert_eq!(recorder.resolved_vs_requested(), (0, 0));
+
+        // The default device may be unavailable on this host.
+        if let Ok(recorder) = PvRecorderBuilder::new(512).init() {
+            let (requested, resolved) = recorder.resolved_vs_requested();
+            assert_eq!(requested, -1);
+            assert!(resolved >= -1);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_dual() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+        let (native, resampled) = recorder.read_dual(recorder.sample_rate() * 3)?;
+        assert_eq!(native.len(), 512);
+        assert_eq!(resampled.len(), 1536);
+        assert_eq!(resampled[0], native[0]);
+        assert_eq!(recorder.samples_read(), 512);
+
+        assert!(recorder.read_dual(0).is_err());
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_application_name() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .application_name("pv_recorder tests")
+            .init()?;
+        assert!(std::env::var_os("PULSE_PROP_application.name").is_none());
+
+        recorder.start()?;
+        assert_eq!(recorder.read()?.len(), 512);
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_estimated_latency() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .buffered_frames_count(10)
+            .init()?;
+        // 10 frames of 512 samples at 16 kHz.
+        assert_eq!(
+            recorder.estimated_latency(),
+            std::time::Duration::from_millis(320)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_into_uninit() -> Result<(), PvRecorderError> {
+        use std::mem::MaybeUninit;
+
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+
+        let mut buffer = [MaybeUninit::<i16>::uninit(); 600];
+        let frame = recorder.read_into_uninit(&mut buffer)?;
+        assert_eq!(frame.len(), 512);
+        assert_eq!(frame, recorder.last_frame().as_slice());
+
+        let mut small = [MaybeUninit::<i16>::uninit(); 100];
+        let result = recorder.read_into_uninit(&mut small);
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        recorder.stop()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_auto_start() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert!(!recorder.is_recording());
+
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .auto_start(true)
+            .init()?;
+        assert!(recorder.is_recording());
+        assert_eq!(recorder.read()?.len(), 512);
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sample_rate_is_default() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert_eq!(
+            recorder.sample_rate_is_default(),
+            recorder.sample_rate() == 16000
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_first_working() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).init_first_working()?;
+        assert!(recorder.device_index() >= 0);
+        recorder.start()?;
+        assert_eq!(recorder.read()?.len(), 512);
+        recorder.stop()?;
+
+        let result = PvRecorderBuilder::new(0).init_first_working();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_with_alloc() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+
+        let mut requested = 0;
+        let frame = recorder.read_with_alloc(|n| {
+            requested = n;
+            vec![0; n + 8]
+        })?;
+        assert_eq!(requested, 512);
+        assert_eq!(frame.len(), 512);
+
+        let result = recorder.read_with_alloc(|n| vec![0; n - 1]);
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        recorder.stop()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_init_missing_library() {
+        let result = PvRecorderBuilder::new(512)
+            .library_path(std::path::Path::new("does/not/exist.so"))
+            .init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(
+                err.status(),
+                PvRecorderErrorStatus::LibraryLoadError
+            ));
+            assert_eq!(
+                err.message(),
+                "pvrecorder library not found at does/not/exist.so"
+            );
+        }
+    }
+
+    #[test]
+    fn test_read_rate() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert!(recorder.read_rate().abs() < f32::EPSILON);
+
+        recorder.start()?;
+        for _ in 0..10 {
+            recorder.read()?;
+        }
+        assert!(recorder.read_rate() > 0.0);
+
+        recorder.reset_counters();
+        assert!(recorder.read_rate().abs() < f32::EPSILON);
+
+        recorder.stop()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_capabilities() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let capabilities = recorder.capabilities();
+        assert!(!capabilities.has_buffered_count);
+        assert!(!capabilities.has_latency_query);
+        Ok(())
+    }
+
+    #[test]
+    fn test_error_from_status() {
+        let err = PvRecorderError::from(PvRecorderStatus::IO_ERROR);
+        assert!(matches!(
+            err.status(),
+            PvRecorderErrorStatus::LibraryError(PvRecorderStatus::IO_ERROR)
+        ));
+        assert_eq!(err.message(), "pvrecorder library returned IO_ERROR");
+    }
+
+    #[test]
+    fn test_io_retry_read() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .io_retry(3, std::time::Duration::from_millis(10))
+            .init()?;
+
+        recorder.start()?;
+        let frame = recorder.read()?;
+        recorder.stop()?;
+
+        assert_eq!(frame.len(), 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_size_bytes() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert_eq!(recorder.bytes_per_sample(), 2);
+        assert_eq!(recorder.frame_size_bytes(), 1024);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_until() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_secs(2);
+        let frame = recorder.read_until(deadline)?;
+        assert_eq!(frame.map(|frame| frame.len()), Some(512));
+
+        let elapsed = std::time::Instant::now();
+        assert!(recorder.read_until(elapsed)?.is_none());
+
+        recorder.stop()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_until_timeout_keeps_frame() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+
+        let deadline = std::time::Instant::now() + std::time::Duration::from_micros(1);
+        let timed_out = recorder.read_until(deadline)?.is_none();
+        let frame = recorder.read()?;
+        let samples_read = recorder.samples_read();
+        recorder.stop()?;
+
+        assert_eq!(frame.len(), 512);
+        let frames_read = if timed_out { 1 } else { 2 };
+        assert_eq!(samples_read, frames_read * 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_silence_library_stderr() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .silence_library_stderr(true)
+            .init()?;
+
+        recorder.start()?;
+        assert_eq!(recorder.read()?.len(), 512);
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_strict_single_thread() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .strict_single_thread(true)
+            .init()?;
+        assert!(!recorder.started_on_current_thread());
+
+        recorder.start()?;
+        assert!(recorder.started_on_current_thread());
+        assert_eq!(recorder.read()?.len(), 512);
+
+        let other = recorder.clone();
+        let result = std::thread::spawn(move || {
+            assert!(!other.started_on_current_thread());
+            other.read()
+        })
+        .join()
+        .expect("reader thread panicked");
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::OtherError));
+        }
+
+        recorder.stop()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_signal_range() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert_eq!(recorder.signal_range(), (0, 0));
+
+        recorder.start()?;
+        let frame = recorder.read()?;
+        let (min, max) = recorder.signal_range();
+        assert!(min <= max);
+        assert!(frame.iter().all(|&sample| (min..=max).contains(&sample)));
+        recorder.stop()?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_stop_draining() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+
+        assert_eq!(recorder.read_exact_samples(100)?.len(), 100);
+        let drained = recorder.stop_draining()?;
+        assert_eq!(drained.len(), 412);
+        assert!(!recorder.is_recording());
+
+        recorder.start()?;
+        assert!(recorder.stop_draining()?.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_frame_duration() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::default()
+            .device_index(0)
+            .frame_duration(std::time::Duration::from_millis(20))
+            .init()?;
+        assert_eq!(recorder.frame_length(), recorder.sample_rate() / 50);
+
+        let recorder = PvRecorderBuilder::default()
+            .device_index(0)
+            .frame_duration(std::time::Duration::from_millis(20))
+            .frame_length(256)
+            .init()?;
+        assert_eq!(recorder.frame_length(), 256);
+
+        let result = PvRecorderBuilder::default()
+            .device_index(0)
+            .frame_duration(std::time::Duration::from_nanos(1))
+            .init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_available_devices_retry() -> Result<(), PvRecorderError> {
+        let builder = PvRecorderBuilder::default();
+        let devices =
+            builder.get_available_devices_retry(3, std::time::Duration::from_millis(10))?;
+        assert_eq!(devices, builder.get_available_devices()?);
+
+        let result = PvRecorderBuilder::default()
+            .library_path(std::path::Path::new("does/not/exist.so"))
+            .get_available_devices_retry(3, std::time::Duration::from_secs(10));
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(
+                err.status(),
+                PvRecorderErrorStatus::LibraryLoadError
+            ));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_stalled() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .stall_frames(2)
+            .init()?;
+        recorder.start()?;
+        assert!(!recorder.is_stalled());
+
+        let first = recorder.read()?;
+        assert!(!recorder.is_stalled());
+        let second = recorder.read()?;
+        assert_eq!(recorder.is_stalled(), first == second);
+        recorder.stop()?;
+
+        let result = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .stall_frames(1)
+            .init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_library_paths() -> Result<(), PvRecorderError> {
+        let valid = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .init()?
+            .library_path()
+            .to_path_buf();
+        let missing = std::path::PathBuf::from("does/not/exist.so");
+
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .library_paths(&[missing.clone(), valid.clone()])
+            .init()?;
+        assert_eq!(recorder.library_path(), valid.as_path());
+
+        let result = PvRecorderBuilder::new(512)
+            .library_paths(&[missing.clone(), missing])
+            .init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(
+                err.status(),
+                PvRecorderErrorStatus::LibraryLoadError
+            ));
+            assert!(err.message().contains("does/not/exist.so"));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_as_raw() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let handle = unsafe { recorder.as_raw() };
+        assert!(!handle.is_null());
+        assert_eq!(unsafe { recorder.clone().as_raw() }, handle);
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_started() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert!(!recorder.is_started());
+
+        recorder.start()?;
+        assert!(recorder.is_started());
+        assert!(recorder.is_recording());
+
+        recorder.stop()?;
+        assert!(!recorder.is_started());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_buffer_check_policy_error() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .buffer_check_policy(pv_recorder::BufferCheckPolicy::Error)
+            .init()?;
+        recorder.start()?;
+
+        let mut buffer = vec![0i16; 256];
+        let result = recorder.read_into(&mut buffer);
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        let mut buffer = vec![0i16; 512];
+        recorder.read_into(&mut buffer)?;
+
+        recorder.stop()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_audio_devices_default_flag() -> Result<(), PvRecorderError> {
+        let devices = PvRecorderBuilder::default().get_audio_devices()?;
+        let defaults: Vec<_> = devices.iter().filter(|device| device.is_default).collect();
+        assert!(defaults.len() <= 1);
+
+        match PvRecorderBuilder::default().init() {
+            Ok(recorder) => {
+                if let Some(device) = defaults.first() {
+                    assert_eq!(device.name, recorder.selected_device());
+                }
+            }
+            Err(_) => assert!(defaults.is_empty()),
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_capture_until_silence() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let sample_rate = recorder.sample_rate();
+
+        // Nothing is silent below a threshold of 0, so this runs to max_duration.
+        let audio = recorder.capture_until_silence(
+            0.0,
+            std::time::Duration::from_millis(10),
+            std::time::Duration::from_millis(100),
+        )?;
+        assert_eq!(audio.len(), sample_rate / 10);
+        assert!(!recorder.is_recording());
+
+        // Everything is silent above full scale, so this stops after the first frame.
+        let audio = recorder.capture_until_silence(
+            2.0,
+            std::time::Duration::from_millis(10),
+            std::time::Duration::from_secs(1),
+        )?;
+        assert_eq!(audio.len(), 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_version_info() -> Result<(), PvRecorderError> {
+        assert_eq!(
+            pv_recorder::PvRecorder::crate_version(),
+            env!("CARGO_PKG_VERSION")
+        );
+
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let info = recorder.version_info();
+        assert_eq!(info.crate_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(info.library_version, recorder.version());
+        assert!(info.to_string().contains(recorder.version()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_exclusive_mode_unsupported() -> Result<(), PvRecorderError> {
+        let result = PvRecorderBuilder::new(512)
+            .device_index(0)
+            .exclusive_mode(true)
+            .init();
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+
+        PvRecorderBuilder::new(512)
+            .device_index(0)
+            .exclusive_mode(false)
+            .init()?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_buffer_frames_multiple() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(256)
+            .device_index(0)
+            .buffer_frames_multiple(4)
+            .init()?;
+        assert_eq!(recorder.buffered_frames_count(), 4);
+        assert_eq!(recorder.buffered_samples(), 1024);
+        Ok(())
+    }
+
+    #[test]
+    fn test_channels_in_debug_and_display() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert_eq!(recorder.channels(), 1);
+        assert!(format!("{recorder:?}").contains("channels: 1"));
+
+        let display = recorder.to_string();
+        assert!(display.contains(recorder.selected_device()));
+        assert!(display.contains("1 channel(s)"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_samples_into() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(256).device_index(0).init()?;
+        recorder.start()?;
+
+        let mut out = vec![i16::MAX; 256 * 3 + 100];
+        let written = recorder.read_samples_into(&mut out)?;
+        let mut small = vec![0i16; 100];
+        let none = recorder.read_samples_into(&mut small)?;
+        recorder.stop()?;
+
+        assert_eq!(written, 256 * 3);
+        assert!(out[written..].iter().all(|&sample| sample == i16::MAX));
+        assert_eq!(none, 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_backend_name() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let name = recorder.backend_name();
+        if cfg!(target_os = "linux") {
+            assert!(
+                name.map_or(true, |name| ["pipewire-pulse", "pulseaudio", "alsa"]
+                    .contains(&name))
+            );
+        } else {
+            assert!(name.is_some());
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_on_frame_observer() -> Result<(), PvRecorderError> {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+        use std::sync::Arc;
+
+        let samples_seen = Arc::new(AtomicUsize::new(0));
+        let observer_count = Arc::clone(&samples_seen);
+        let recorder = PvRecorderBuilder::new(256)
+            .device_index(0)
+            .on_frame(Arc::new(move |frame: &[i16]| {
+                observer_count.fetch_add(frame.len(), Ordering::Relaxed);
+            }))
+            .init()?;
+        recorder.start()?;
+
+        recorder.read()?;
+        let mut buffer = vec![0i16; 256];
+        recorder.read_into(&mut buffer)?;
+        recorder.stop()?;
+
+        assert_eq!(samples_seen.load(Ordering::Relaxed), 512);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_last_frame_valid_samples() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(256).device_index(0).init()?;
+        assert_eq!(recorder.last_frame_valid_samples(), 0);
+        recorder.start()?;
+
+        recorder.read()?;
+        assert_eq!(recorder.last_frame_valid_samples(), 256);
+
+        recorder.read_exact_samples(100)?;
+        let drained = recorder.stop_draining()?;
+        assert_eq!(drained.len(), 156);
+        assert_eq!(recorder.last_frame_valid_samples(), 156);
+
+        Ok(())
+    }
 }
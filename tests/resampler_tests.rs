@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use pv_recorder::{PvRecorderBuilder, PvRecorderError, PvRecorderErrorStatus, Resampler};
+
+    #[test]
+    fn test_resampler_continuous_across_frames() -> Result<(), PvRecorderError> {
+        let input: Vec<i16> = (0..30).map(|n| n * 100).collect();
+
+        let mut whole = Resampler::new(16000, 10000)?;
+        let expected = whole.process(&input);
+
+        let mut chunked = Resampler::new(16000, 10000)?;
+        let output: Vec<i16> = input
+            .chunks(7)
+            .flat_map(|frame| chunked.process(frame))
+            .collect();
+        assert_eq!(output, expected);
+
+        // A linear ramp stays exactly linear, including across frame boundaries.
+        let mut upsampler = Resampler::new(8000, 16000)?;
+        let mut output = upsampler.process(&[0, 100, 200]);
+        output.extend(upsampler.process(&[300, 400]));
+        assert_eq!(output, vec![0, 50, 100, 150, 200, 250, 300, 350]);
+
+        upsampler.reset();
+        assert_eq!(upsampler.process(&[0, 100]), vec![0, 50]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_resampler_invalid_rate() {
+        let result = Resampler::new(16000, 0);
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(err.status(), PvRecorderErrorStatus::ArgumentError));
+        }
+    }
+
+    #[test]
+    fn test_read_resampled_streaming() -> Result<(), PvRecorderError> {
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        recorder.start()?;
+
+        let mut resampler = Resampler::new(recorder.sample_rate(), recorder.sample_rate() / 2)?;
+        let total: usize = (0..4)
+            .map(|_| {
+                recorder
+                    .read_resampled_streaming(&mut resampler)
+                    .map(|frame| frame.len())
+            })
+            .sum::<Result<_, _>>()?;
+        assert_eq!(total, 1024);
+
+        let mut mismatched = Resampler::new(recorder.sample_rate() + 1, 8000)?;
+        assert!(recorder.read_resampled_streaming(&mut mismatched).is_err());
+
+        recorder.stop()?;
+        Ok(())
+    }
+}
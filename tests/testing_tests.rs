@@ -0,0 +1,36 @@
+#![cfg(feature = "testing")]
+
+#[cfg(test)]
+mod tests {
+    use pv_recorder::testing::SineSource;
+    use pv_recorder::RecorderBackend;
+
+    #[test]
+    fn test_sine_source_frames() -> Result<(), pv_recorder::PvRecorderError> {
+        let mut source = SineSource::new(1000.0, 0.5)
+            .with_sample_rate(8000)
+            .with_frame_length(8);
+        assert_eq!(source.frame_length(), 8);
+        assert_eq!(source.sample_rate(), 8000);
+
+        let frame = source.read()?;
+        assert_eq!(
+            frame,
+            vec![0, 11585, 16384, 11585, 0, -11585, -16384, -11585]
+        );
+        assert_eq!(source.read()?, frame);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_sine_source_is_deterministic() -> Result<(), pv_recorder::PvRecorderError> {
+        let mut first = SineSource::new(440.0, 1.0);
+        let mut second = SineSource::new(440.0, 1.0);
+        for _ in 0..3 {
+            assert_eq!(first.read()?, second.read()?);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,10 @@
+#[cfg(test)]
+mod tests {
+    use pv_recorder::set_warning_handler;
+
+    #[test]
+    fn test_set_warning_handler_once() {
+        assert!(set_warning_handler(Box::new(|_| {})).is_ok());
+        assert!(set_warning_handler(Box::new(|_| {})).is_err());
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "wav")]
+
+#[cfg(test)]
+mod tests {
+    use pv_recorder::{PvRecorderBuilder, PvRecorderError, WavSink};
+
+    #[test]
+    fn test_wav_sink() -> Result<(), PvRecorderError> {
+        let path = std::env::temp_dir().join("pv_recorder_wav_sink_test.wav");
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let sample_rate = recorder.sample_rate();
+        recorder.start()?;
+
+        let mut sink = WavSink::create(&path, recorder.clone())?;
+        assert!(sink.is_empty());
+        sink.push_frame()?;
+        sink.push_frame()?;
+        assert_eq!(sink.len(), 1024);
+        sink.finalize()?;
+        recorder.stop()?;
+
+        let reader = hound::WavReader::open(&path).expect("failed to open WAV file");
+        let spec = reader.spec();
+        assert_eq!(spec.channels, 1);
+        assert_eq!(spec.bits_per_sample, 16);
+        assert_eq!(spec.sample_rate as usize, sample_rate);
+        assert_eq!(reader.len(), 1024);
+
+        let _ = std::fs::remove_file(&path);
+        Ok(())
+    }
+
+    #[cfg(feature = "base64")]
+    #[test]
+    fn test_capture_wav_base64() -> Result<(), PvRecorderError> {
+        use base64::Engine;
+
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        let encoded = recorder.capture_wav_base64(std::time::Duration::from_millis(100))?;
+
+        let bytes = base64::engine::general_purpose::STANDARD
+            .decode(encoded)
+            .expect("invalid base64");
+        let reader =
+            hound::WavReader::new(std::io::Cursor::new(bytes)).expect("failed to parse WAV");
+        assert_eq!(reader.spec().channels, 1);
+        assert_eq!(reader.len() as usize, recorder.sample_rate() / 10);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_stereo_split_wav_needs_two_channels() -> Result<(), PvRecorderError> {
+        let dir = std::env::temp_dir();
+        let left = dir.join("pv_recorder_split_left.wav");
+        let right = dir.join("pv_recorder_split_right.wav");
+        let recorder = PvRecorderBuilder::new(512).device_index(0).init()?;
+        assert_eq!(recorder.channels(), 1);
+
+        let result =
+            recorder.write_stereo_split_wav(&left, &right, std::time::Duration::from_millis(100));
+        assert!(result.is_err());
+        if let Err(err) = result {
+            assert!(matches!(
+                err.status(),
+                pv_recorder::PvRecorderErrorStatus::ArgumentError
+            ));
+        }
+        assert!(!left.exists());
+
+        Ok(())
+    }
+}